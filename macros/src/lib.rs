@@ -0,0 +1,242 @@
+//! Procedural macros for the [`constcat`] crate.
+//!
+//! This crate is an implementation detail, use the macros re-exported by
+//! [`constcat`] instead.
+//!
+//! [`constcat`]: https://docs.rs/constcat
+
+use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use proc_macro::{Delimiter, Group, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Expands a glob pattern into a call to another macro with the matched paths.
+///
+/// The input must be in the form `[callback!] [prefix] "pattern"`. The output
+/// is `callback!(prefix "path0", "path1", ...)` where the paths are all files
+/// matching the pattern, relative to `CARGO_MANIFEST_DIR`, in sorted order.
+/// The callback is responsible for resolving the paths against
+/// `CARGO_MANIFEST_DIR`.
+#[proc_macro]
+pub fn include_glob(input: TokenStream) -> TokenStream {
+    match expand(input) {
+        Ok(output) => output,
+        Err((span, msg)) => compile_error(span, msg),
+    }
+}
+
+type Error = (Span, String);
+
+fn expand(input: TokenStream) -> Result<TokenStream, Error> {
+    let mut iter = input.into_iter();
+    let callback = expect_bracketed(iter.next())?;
+    let prefix = expect_bracketed(iter.next())?;
+    let (span, pattern) = match unwrap_none_group(iter.next()) {
+        Some(TokenTree::Literal(lit)) => (lit.span(), parse_str(&lit.to_string())),
+        tt => return Err(error(tt, "expected a string literal")),
+    };
+    let pattern = pattern.ok_or_else(|| (span, "expected a string literal".to_owned()))?;
+    if let Some(tt) = iter.next() {
+        return Err(error(Some(tt), "unexpected token"));
+    }
+
+    let root = env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .ok_or_else(|| (span, "`CARGO_MANIFEST_DIR` is not set".to_owned()))?;
+    let mut paths = Vec::new();
+    let components: Vec<&str> = pattern.split('/').filter(|c| !c.is_empty()).collect();
+    walk(&root, &components, &mut paths).map_err(|err| (span, err.to_string()))?;
+    paths.sort();
+    paths.dedup();
+
+    let mut args = prefix;
+    for (i, path) in paths.iter().enumerate() {
+        if i > 0 {
+            args.extend([TokenTree::Punct(Punct::new(',', Spacing::Alone))]);
+        }
+        let path = relative(&root, path).map_err(|msg| (span, msg))?;
+        let mut lit = Literal::string(&path);
+        lit.set_span(span);
+        args.extend([TokenTree::Literal(lit)]);
+    }
+
+    let mut output = callback;
+    output.extend([TokenTree::Group(Group::new(Delimiter::Parenthesis, args))]);
+    Ok(output)
+}
+
+/// Recursively finds all files under `dir` that match the pattern components.
+fn walk(dir: &Path, components: &[&str], paths: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    let (first, rest) = match components.split_first() {
+        Some(split) => split,
+        None => {
+            if dir.is_file() {
+                paths.push(dir.to_owned());
+            }
+            return Ok(());
+        }
+    };
+
+    match *first {
+        "." => walk(dir, rest, paths),
+        ".." => walk(&dir.join(".."), rest, paths),
+        "**" => {
+            walk(dir, rest, paths)?;
+            for entry in read_dir_sorted(dir)? {
+                if is_recursable(&entry)? {
+                    walk(&entry, components, paths)?;
+                }
+            }
+            Ok(())
+        }
+        c if !c.contains(['*', '?']) => {
+            let path = dir.join(c);
+            if path.exists() {
+                walk(&path, rest, paths)?;
+            }
+            Ok(())
+        }
+        c => {
+            for entry in read_dir_sorted(dir)? {
+                let matched = entry
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| matches(c.as_bytes(), name.as_bytes()));
+                if matched {
+                    walk(&entry, rest, paths)?;
+                }
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Returns whether `**` should descend into the path.
+///
+/// Hidden directories and `target` are skipped, and symlinks are not followed
+/// so that a link to a parent directory can not cause an infinite loop.
+fn is_recursable(path: &Path) -> std::io::Result<bool> {
+    let hidden = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with('.') || name == "target");
+    Ok(!hidden && fs::symlink_metadata(path)?.is_dir())
+}
+
+/// Returns the path relative to `root` using `/` as the separator.
+fn relative(root: &Path, path: &Path) -> Result<String, String> {
+    let relative = path.strip_prefix(root).map_err(|_| {
+        format!(
+            "matched file is outside of `CARGO_MANIFEST_DIR`: {}",
+            path.display()
+        )
+    })?;
+    let components = relative
+        .components()
+        .map(|c| c.as_os_str().to_str())
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| format!("path is not valid UTF-8: {}", path.display()))?;
+    Ok(components.join("/"))
+}
+
+fn read_dir_sorted(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+    entries.sort();
+    Ok(entries)
+}
+
+/// Matches a single path component against a pattern containing `*` and `?`.
+fn matches(pattern: &[u8], name: &[u8]) -> bool {
+    match (pattern.split_first(), name.split_first()) {
+        (None, None) => true,
+        (Some((b'*', p)), _) => {
+            matches(p, name) || name.split_first().is_some_and(|(_, n)| matches(pattern, n))
+        }
+        (Some((b'?', p)), Some((_, n))) => matches(p, n),
+        (Some((a, p)), Some((b, n))) => a == b && matches(p, n),
+        _ => false,
+    }
+}
+
+/// Parses the contents of a string literal token.
+fn parse_str(lit: &str) -> Option<String> {
+    if let Some(raw) = lit.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let inner = &raw[hashes..raw.len() - hashes];
+        return Some(inner.strip_prefix('"')?.strip_suffix('"')?.to_owned());
+    }
+    let inner = lit.strip_prefix('"')?.strip_suffix('"')?;
+    let mut s = String::new();
+    let mut chars = inner.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            s.push(c);
+            continue;
+        }
+        match chars.next()? {
+            'n' => s.push('\n'),
+            'r' => s.push('\r'),
+            't' => s.push('\t'),
+            '0' => s.push('\0'),
+            '\\' => s.push('\\'),
+            '"' => s.push('"'),
+            '\'' => s.push('\''),
+            'x' => {
+                let hex: String = chars.by_ref().take(2).collect();
+                s.push(char::from(u8::from_str_radix(&hex, 16).ok()?));
+            }
+            'u' => {
+                let hex: String = chars.by_ref().skip(1).take_while(|&c| c != '}').collect();
+                s.push(char::from_u32(
+                    u32::from_str_radix(&hex.replace('_', ""), 16).ok()?,
+                )?);
+            }
+            '\n' => while chars.next_if(|c| c.is_whitespace()).is_some() {},
+            _ => return None,
+        }
+    }
+    Some(s)
+}
+
+/// Unwraps an invisible group, these are produced when `macro_rules!` forwards
+/// a fragment like `$pattern:literal` to a procedural macro.
+fn unwrap_none_group(tt: Option<TokenTree>) -> Option<TokenTree> {
+    match tt {
+        Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::None => {
+            let mut iter = g.stream().into_iter();
+            match (iter.next(), iter.next()) {
+                (Some(tt), None) => Some(tt),
+                _ => Some(TokenTree::Group(g)),
+            }
+        }
+        tt => tt,
+    }
+}
+
+fn expect_bracketed(tt: Option<TokenTree>) -> Result<TokenStream, Error> {
+    match tt {
+        Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Bracket => Ok(g.stream()),
+        tt => Err(error(tt, "expected `[`")),
+    }
+}
+
+fn error(tt: Option<TokenTree>, msg: impl Display) -> Error {
+    let span = tt.map_or_else(Span::call_site, |tt| tt.span());
+    (span, msg.to_string())
+}
+
+fn compile_error(span: Span, msg: String) -> TokenStream {
+    let mut path: TokenStream = "::core::compile_error!".parse().unwrap();
+    let mut lit = Literal::string(&msg);
+    lit.set_span(span);
+    let args = TokenStream::from(TokenTree::Literal(lit));
+    path.extend([TokenTree::Group(Group::new(Delimiter::Parenthesis, args))]);
+    path
+}
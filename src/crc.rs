@@ -0,0 +1,79 @@
+/// The parameters of a CRC-16 algorithm.
+///
+/// This is used by the [`crc16!`] macro. Parameters are given in the usual
+/// Rocksoft model, common algorithms are available as associated constants.
+///
+/// [`crc16!`]: crate::crc16
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Crc16 {
+    /// The generator polynomial, without the leading `x^16` term.
+    pub poly: u16,
+    /// The initial value of the register.
+    pub init: u16,
+    /// Whether each input byte is reflected before processing.
+    pub reflect_in: bool,
+    /// Whether the final register value is reflected.
+    pub reflect_out: bool,
+    /// The value XORed with the final register value.
+    pub xor_out: u16,
+}
+
+impl Crc16 {
+    /// CRC-16/ARC, also known as CRC-16/IBM.
+    pub const ARC: Self = Self::new(0x8005, 0x0000, true, 0x0000);
+
+    /// CRC-16/MODBUS, used by Modbus RTU.
+    pub const MODBUS: Self = Self::new(0x8005, 0xffff, true, 0x0000);
+
+    /// CRC-16/IBM-3740, commonly known as CRC-16/CCITT-FALSE.
+    pub const IBM_3740: Self = Self::new(0x1021, 0xffff, false, 0x0000);
+
+    /// CRC-16/XMODEM.
+    pub const XMODEM: Self = Self::new(0x1021, 0x0000, false, 0x0000);
+
+    /// CRC-16/KERMIT, commonly known as CRC-16/CCITT.
+    pub const KERMIT: Self = Self::new(0x1021, 0x0000, true, 0x0000);
+
+    /// CRC-16/IBM-SDLC, also known as CRC-16/X-25.
+    pub const IBM_SDLC: Self = Self::new(0x1021, 0xffff, true, 0xffff);
+
+    /// Returns the parameters for a CRC-16 algorithm that reflects both the
+    /// input and output or neither.
+    pub const fn new(poly: u16, init: u16, reflect: bool, xor_out: u16) -> Self {
+        Self {
+            poly,
+            init,
+            reflect_in: reflect,
+            reflect_out: reflect,
+            xor_out,
+        }
+    }
+
+    /// Computes the checksum of the bytes.
+    pub const fn checksum(&self, bytes: &[u8]) -> u16 {
+        let mut crc = self.init;
+        let mut i = 0;
+        while i < bytes.len() {
+            let b = if self.reflect_in {
+                bytes[i].reverse_bits()
+            } else {
+                bytes[i]
+            };
+            crc ^= (b as u16) << 8;
+            let mut k = 0;
+            while k < 8 {
+                crc = if crc & 0x8000 != 0 {
+                    (crc << 1) ^ self.poly
+                } else {
+                    crc << 1
+                };
+                k += 1;
+            }
+            i += 1;
+        }
+        if self.reflect_out {
+            crc = crc.reverse_bits();
+        }
+        crc ^ self.xor_out
+    }
+}
@@ -0,0 +1,39 @@
+use crate::private::{Be, Le};
+
+/// Encode an integer, or a slice or array of integers, in little-endian byte
+/// order.
+///
+/// This is used as an argument to [`concat_bytes!`] or any macro that accepts
+/// the same arguments. Integer literals must have a type suffix, for example
+/// `le(1u16)`.
+///
+/// ```
+/// # use constcat::{concat_bytes, le};
+/// #
+/// const HEADER: &[u8] = concat_bytes!(b"v", le(0x0102u16), le([3u8, 4]));
+/// assert_eq!(HEADER, b"v\x02\x01\x03\x04");
+/// ```
+///
+/// [`concat_bytes!`]: crate::concat_bytes
+pub const fn le<T>(value: T) -> Le<T> {
+    Le(value)
+}
+
+/// Encode an integer, or a slice or array of integers, in big-endian byte
+/// order.
+///
+/// This is used as an argument to [`concat_bytes!`] or any macro that accepts
+/// the same arguments. Integer literals must have a type suffix, for example
+/// `be(1u16)`.
+///
+/// ```
+/// # use constcat::{be, concat_bytes};
+/// #
+/// const HEADER: &[u8] = concat_bytes!(b"v", be(0x0102u16), be(-1i8));
+/// assert_eq!(HEADER, b"v\x01\x02\xff");
+/// ```
+///
+/// [`concat_bytes!`]: crate::concat_bytes
+pub const fn be<T>(value: T) -> Be<T> {
+    Be(value)
+}
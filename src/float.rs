@@ -0,0 +1,400 @@
+//! Exact decimal formatting of floating point numbers.
+//!
+//! The shortest representation is found using the free-format algorithm from
+//! "Printing Floating-Point Numbers Quickly and Accurately" by Burger and
+//! Dybvig, using a fixed size big integer that is large enough for any `f64`.
+
+#![allow(clippy::manual_is_multiple_of)]
+
+use crate::private::{fmt_num, Buffer, NumFmt};
+
+////////////////////////////////////////////////////////////////////////////////
+// Big integers
+////////////////////////////////////////////////////////////////////////////////
+
+const LIMBS: usize = 40;
+
+/// An unsigned integer with little-endian 32-bit limbs.
+#[derive(Clone, Copy)]
+struct Big([u32; LIMBS]);
+
+impl Big {
+    const fn new(n: u64) -> Self {
+        let mut limbs = [0; LIMBS];
+        limbs[0] = n as u32;
+        limbs[1] = (n >> 32) as u32;
+        Self(limbs)
+    }
+
+    const fn is_zero(&self) -> bool {
+        let mut i = 0;
+        while i < LIMBS {
+            if self.0[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const fn is_odd(&self) -> bool {
+        self.0[0] & 1 == 1
+    }
+
+    /// Compares the two integers returning -1, 0, or 1.
+    const fn cmp(&self, other: &Self) -> i32 {
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            if self.0[i] != other.0[i] {
+                return if self.0[i] < other.0[i] { -1 } else { 1 };
+            }
+        }
+        0
+    }
+
+    const fn add(mut self, other: &Self) -> Self {
+        let mut carry = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            let sum = self.0[i] as u64 + other.0[i] as u64 + carry;
+            self.0[i] = sum as u32;
+            carry = sum >> 32;
+            i += 1;
+        }
+        if carry != 0 {
+            panic!("number is too large to format");
+        }
+        self
+    }
+
+    /// Subtracts `other` which must not be larger than `self`.
+    const fn sub(mut self, other: &Self) -> Self {
+        let mut borrow = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            let diff = self.0[i] as i64 - other.0[i] as i64 - borrow;
+            self.0[i] = diff as u32;
+            borrow = (diff < 0) as i64;
+            i += 1;
+        }
+        self
+    }
+
+    const fn mul_small(mut self, m: u32) -> Self {
+        let mut carry = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            let prod = self.0[i] as u64 * m as u64 + carry;
+            self.0[i] = prod as u32;
+            carry = prod >> 32;
+            i += 1;
+        }
+        if carry != 0 {
+            panic!("number is too large to format");
+        }
+        self
+    }
+
+    const fn mul_pow10(mut self, mut n: u32) -> Self {
+        while n >= 9 {
+            self = self.mul_small(1_000_000_000);
+            n -= 9;
+        }
+        self.mul_small(10u32.pow(n))
+    }
+
+    const fn shl(self, n: u32) -> Self {
+        let limbs = (n / 32) as usize;
+        let bits = n % 32;
+        let mut out = [0; LIMBS];
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            if i < limbs {
+                break;
+            }
+            let mut v = self.0[i - limbs] << bits;
+            if bits > 0 && i > limbs {
+                v |= self.0[i - limbs - 1] >> (32 - bits);
+            }
+            out[i] = v;
+        }
+        if limbs > 0 || bits > 0 {
+            // Check that no bits were shifted out of the top.
+            if Self(out).shr(n).cmp(&self) != 0 {
+                panic!("number is too large to format");
+            }
+        }
+        Self(out)
+    }
+
+    const fn shr(self, n: u32) -> Self {
+        let limbs = (n / 32) as usize;
+        let bits = n % 32;
+        let mut out = [0; LIMBS];
+        let mut i = 0;
+        while i + limbs < LIMBS {
+            let mut v = self.0[i + limbs] >> bits;
+            if bits > 0 && i + limbs + 1 < LIMBS {
+                v |= self.0[i + limbs + 1] << (32 - bits);
+            }
+            out[i] = v;
+            i += 1;
+        }
+        Self(out)
+    }
+
+    /// Returns the quotient and remainder of dividing by a small integer.
+    const fn divmod_small(mut self, d: u32) -> (Self, u32) {
+        let mut rem = 0;
+        let mut i = LIMBS;
+        while i > 0 {
+            i -= 1;
+            let cur = (rem << 32) | self.0[i] as u64;
+            self.0[i] = (cur / d as u64) as u32;
+            rem = cur % d as u64;
+        }
+        (self, rem as u32)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Decoding
+////////////////////////////////////////////////////////////////////////////////
+
+/// A floating point number that can be formatted by `fmt_float!`.
+pub struct Float<T>(pub T);
+
+/// A decoded floating point number.
+///
+/// Finite values are equal to `mant * 2^exp`.
+pub struct Decoded {
+    neg: bool,
+    nan: bool,
+    inf: bool,
+    mant: u64,
+    exp: i32,
+    /// Whether the next smaller value is closer than the next larger value.
+    lower_closer: bool,
+}
+
+impl Float<f64> {
+    pub const fn decode(&self) -> Decoded {
+        decode(self.0.to_bits(), 52, 11)
+    }
+}
+
+impl Float<f32> {
+    pub const fn decode(&self) -> Decoded {
+        decode(self.0.to_bits() as u64, 23, 8)
+    }
+}
+
+const fn decode(bits: u64, mant_bits: u32, exp_bits: u32) -> Decoded {
+    let neg = bits >> (mant_bits + exp_bits) & 1 == 1;
+    let biased = (bits >> mant_bits) as i32 & ((1 << exp_bits) - 1);
+    let frac = bits & ((1 << mant_bits) - 1);
+    let bias = (1 << (exp_bits - 1)) - 1;
+    let mut d = Decoded {
+        neg,
+        nan: false,
+        inf: false,
+        mant: frac,
+        exp: 1 - bias - mant_bits as i32,
+        lower_closer: false,
+    };
+    if biased == (1 << exp_bits) - 1 {
+        d.nan = frac != 0;
+        d.inf = frac == 0;
+    } else if biased != 0 {
+        d.mant = frac | 1 << mant_bits;
+        d.exp = biased - bias - mant_bits as i32;
+        d.lower_closer = frac == 0 && biased > 1;
+    }
+    d
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Formatting
+////////////////////////////////////////////////////////////////////////////////
+
+/// Formats the number like `Display`, either using the shortest representation
+/// that round trips or using exactly `precision` fractional digits.
+pub const fn fmt_float<const N: usize>(
+    d: Decoded,
+    precision: Option<usize>,
+    f: NumFmt,
+) -> Buffer<u8, N> {
+    let mut buf: Buffer<u8, 512> = Buffer::new(0);
+    if d.nan {
+        buf.extend(b"NaN");
+    } else if d.inf {
+        buf.extend(b"inf");
+    } else {
+        match precision {
+            Some(p) => fixed(&mut buf, &d, p),
+            None if d.mant == 0 => buf.push(b'0'),
+            None => shortest(&mut buf, &d),
+        }
+    }
+    if buf.len > 512 {
+        panic!("number is too large to format");
+    }
+    let (body, _) = buf.buf.split_at(buf.len);
+    fmt_num(body, d.neg && !d.nan, f)
+}
+
+const fn push_zeros<const N: usize>(buf: &mut Buffer<u8, N>, n: usize) {
+    let mut i = 0;
+    while i < n {
+        buf.push(b'0');
+        i += 1;
+    }
+}
+
+/// Writes the shortest digits that uniquely identify the number.
+const fn shortest<const N: usize>(buf: &mut Buffer<u8, N>, d: &Decoded) {
+    // The value is `r / s` and the distances to the midpoints between the next
+    // smaller and larger values are `m_minus / s` and `m_plus / s`.
+    let f = Big::new(d.mant);
+    let (mut r, mut s, mut m_plus, mut m_minus) = if d.exp >= 0 {
+        let be = Big::new(1).shl(d.exp as u32);
+        if d.lower_closer {
+            (f.shl(d.exp as u32 + 2), Big::new(4), be.shl(1), be)
+        } else {
+            (f.shl(d.exp as u32 + 1), Big::new(2), be, be)
+        }
+    } else if d.lower_closer {
+        (
+            f.shl(2),
+            Big::new(1).shl((2 - d.exp) as u32),
+            Big::new(2),
+            Big::new(1),
+        )
+    } else {
+        (
+            f.shl(1),
+            Big::new(1).shl((1 - d.exp) as u32),
+            Big::new(1),
+            Big::new(1),
+        )
+    };
+    // The boundaries are included if the mantissa is even because then round
+    // half to even parsing would produce this value.
+    let inclusive = d.mant % 2 == 0;
+
+    // Scale so that `0.1 <= (r + m_plus) / s < 1` and the value is
+    // `0.d1d2... * 10^k`.
+    let mut k: i32 = 0;
+    loop {
+        let c = r.add(&m_plus).cmp(&s);
+        if c > 0 || inclusive && c == 0 {
+            s = s.mul_small(10);
+            k += 1;
+        } else {
+            break;
+        }
+    }
+    loop {
+        let c = r.add(&m_plus).mul_small(10).cmp(&s);
+        if c < 0 || !inclusive && c == 0 {
+            r = r.mul_small(10);
+            m_plus = m_plus.mul_small(10);
+            m_minus = m_minus.mul_small(10);
+            k -= 1;
+        } else {
+            break;
+        }
+    }
+
+    let mut digits = [0u8; 20];
+    let mut n = 0;
+    loop {
+        r = r.mul_small(10);
+        m_plus = m_plus.mul_small(10);
+        m_minus = m_minus.mul_small(10);
+        let mut digit = 0;
+        while r.cmp(&s) >= 0 {
+            r = r.sub(&s);
+            digit += 1;
+        }
+        let c = r.cmp(&m_minus);
+        let low = c < 0 || inclusive && c == 0;
+        let c = r.add(&m_plus).cmp(&s);
+        let high = c > 0 || inclusive && c == 0;
+        if low && high {
+            if r.shl(1).cmp(&s) >= 0 {
+                digit += 1;
+            }
+        } else if high {
+            digit += 1;
+        }
+        digits[n] = b'0' + digit;
+        n += 1;
+        if low || high {
+            break;
+        }
+    }
+
+    if k <= 0 {
+        buf.extend(b"0.");
+        push_zeros(buf, -k as usize);
+        let mut i = 0;
+        while i < n {
+            buf.push(digits[i]);
+            i += 1;
+        }
+    } else {
+        let k = k as usize;
+        let mut i = 0;
+        while i < n {
+            if i == k {
+                buf.push(b'.');
+            }
+            buf.push(digits[i]);
+            i += 1;
+        }
+        if k > n {
+            push_zeros(buf, k - n);
+        }
+    }
+}
+
+/// Writes the number rounded half to even with exactly `precision` fractional
+/// digits.
+const fn fixed<const N: usize>(buf: &mut Buffer<u8, N>, d: &Decoded, precision: usize) {
+    // Calculate the number scaled by `10^precision` and rounded to an integer.
+    let mut n = Big::new(d.mant).mul_pow10(precision as u32);
+    if d.exp >= 0 {
+        n = n.shl(d.exp as u32);
+    } else {
+        let shift = d.exp.unsigned_abs();
+        let q = n.shr(shift);
+        let rem = n.sub(&q.shl(shift));
+        let half = Big::new(1).shl(shift - 1);
+        let c = rem.cmp(&half);
+        n = if c > 0 || c == 0 && q.is_odd() {
+            q.add(&Big::new(1))
+        } else {
+            q
+        };
+    }
+
+    // Write the digits in reverse then copy them into the buffer.
+    let mut digits = [0u8; LIMBS * 10];
+    let mut len = 0;
+    while !n.is_zero() || len <= precision {
+        let (q, digit) = n.divmod_small(10);
+        digits[len] = b'0' + digit as u8;
+        len += 1;
+        n = q;
+    }
+    while len > 0 {
+        len -= 1;
+        buf.push(digits[len]);
+        if len == precision && precision > 0 {
+            buf.push(b'.');
+        }
+    }
+}
@@ -0,0 +1,55 @@
+/// Bytes that have been XORed with a key at compile time.
+///
+/// This is produced by the [`obfuscate!`] macro. The original bytes only exist
+/// during constant evaluation, so they do not appear verbatim in the compiled
+/// binary. They are recovered at runtime using [`decode`][Self::decode].
+///
+/// Note that this is obfuscation and not encryption, the key is stored next to
+/// the data.
+///
+/// [`obfuscate!`]: crate::obfuscate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Obfuscated<const N: usize> {
+    data: [u8; N],
+    key: &'static [u8],
+}
+
+impl<const N: usize> Obfuscated<N> {
+    #[doc(hidden)]
+    pub const fn new(bytes: &[u8], key: &'static [u8]) -> Self {
+        if bytes.len() != N {
+            panic!("invalid length");
+        }
+        if key.is_empty() {
+            panic!("key is empty");
+        }
+        let mut data = [0; N];
+        let mut i = 0;
+        while i < N {
+            data[i] = bytes[i] ^ key[i % key.len()];
+            i += 1;
+        }
+        Self { data, key }
+    }
+
+    /// Returns the obfuscated bytes.
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.data
+    }
+
+    /// Returns the key that the bytes were XORed with, repeated as needed.
+    pub const fn key(&self) -> &'static [u8] {
+        self.key
+    }
+
+    /// Returns the original bytes.
+    pub fn decode(&self) -> [u8; N] {
+        // Prevent the compiler from evaluating this at compile time and
+        // storing the original bytes in the binary anyway.
+        let mut bytes = core::hint::black_box(self.data);
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b ^= self.key[i % self.key.len()];
+        }
+        bytes
+    }
+}
@@ -0,0 +1,3308 @@
+//! Private API used by the exported macros.
+//!
+//! Nothing in here is covered by semver.
+
+#![allow(clippy::len_without_is_empty, clippy::manual_is_multiple_of)]
+
+#[cfg(feature = "glob")]
+pub use constcat_macros::include_glob;
+
+pub use crate::float::{fmt_float, Float};
+#[cfg(feature = "width")]
+pub use crate::width::display_width;
+
+////////////////////////////////////////////////////////////////////////////////
+// Buffer
+////////////////////////////////////////////////////////////////////////////////
+
+/// A fixed capacity buffer for building up macro output in const contexts.
+///
+/// Values pushed past the capacity are discarded but still counted. This
+/// allows the same const function to be used to first calculate the length
+/// of the output using a zero capacity buffer, and then to actually build it
+/// using a buffer of exactly that capacity.
+#[derive(Clone, Copy)]
+pub struct Buffer<T: Copy, const N: usize> {
+    pub buf: [T; N],
+    pub len: usize,
+}
+
+impl<T: Copy, const N: usize> Buffer<T, N> {
+    pub const fn new(zero: T) -> Self {
+        Self {
+            buf: [zero; N],
+            len: 0,
+        }
+    }
+
+    pub const fn push(&mut self, value: T) {
+        if self.len < N {
+            self.buf[self.len] = value;
+        }
+        self.len += 1;
+    }
+
+    pub const fn extend(&mut self, values: &[T]) {
+        let mut i = 0;
+        while i < values.len() {
+            self.push(values[i]);
+            i += 1;
+        }
+    }
+
+    pub const fn into_array(self) -> [T; N] {
+        if self.len != N {
+            panic!("invalid length");
+        }
+        self.buf
+    }
+}
+
+/// Copies a slice into an array of the same length.
+pub const fn to_array<T: Copy, const N: usize>(s: &[T]) -> [T; N] {
+    if s.len() != N {
+        panic!("invalid length");
+    }
+    // SAFETY: The slice was asserted to have exactly `N` elements.
+    unsafe { *(s.as_ptr() as *const [T; N]) }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Bytes
+////////////////////////////////////////////////////////////////////////////////
+
+/// A value that can be converted to bytes by `concat_bytes!`.
+///
+/// The conversion is selected by the type of the value using inherent impls,
+/// since trait methods can't be called in const contexts.
+pub struct Bytes<T>(pub T);
+
+impl Bytes<&[u8]> {
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn get(&self, i: usize) -> u8 {
+        self.0[i]
+    }
+}
+
+impl<const N: usize> Bytes<&[u8; N]> {
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    pub const fn get(&self, i: usize) -> u8 {
+        self.0[i]
+    }
+}
+
+impl<const N: usize> Bytes<[u8; N]> {
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    pub const fn get(&self, i: usize) -> u8 {
+        self.0[i]
+    }
+}
+
+impl Bytes<&str> {
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn get(&self, i: usize) -> u8 {
+        self.0.as_bytes()[i]
+    }
+}
+
+impl Bytes<u8> {
+    pub const fn len(&self) -> usize {
+        1
+    }
+
+    pub const fn get(&self, _: usize) -> u8 {
+        self.0
+    }
+}
+
+impl Bytes<char> {
+    pub const fn len(&self) -> usize {
+        self.0.len_utf8()
+    }
+
+    pub const fn get(&self, i: usize) -> u8 {
+        encode_utf8(self.0 as u32).0[i]
+    }
+}
+
+/// An integer or slice of integers to be encoded in little-endian byte order.
+pub struct Le<T>(pub T);
+
+/// An integer or slice of integers to be encoded in big-endian byte order.
+pub struct Be<T>(pub T);
+
+/// An integer or slice of integers to be encoded in native byte order.
+#[cfg(target_endian = "little")]
+pub use Le as Ne;
+
+/// An integer or slice of integers to be encoded in native byte order.
+#[cfg(target_endian = "big")]
+pub use Be as Ne;
+
+macro_rules! impl_bytes_for_int {
+    ($($ty:ty),+) => {$(
+        impl Bytes<Le<$ty>> {
+            pub const fn len(&self) -> usize {
+                core::mem::size_of::<$ty>()
+            }
+
+            pub const fn get(&self, i: usize) -> u8 {
+                self.0 .0.to_le_bytes()[i]
+            }
+        }
+
+        impl Bytes<Be<$ty>> {
+            pub const fn len(&self) -> usize {
+                core::mem::size_of::<$ty>()
+            }
+
+            pub const fn get(&self, i: usize) -> u8 {
+                self.0 .0.to_be_bytes()[i]
+            }
+        }
+
+        impl Bytes<Le<&[$ty]>> {
+            pub const fn len(&self) -> usize {
+                self.0 .0.len() * core::mem::size_of::<$ty>()
+            }
+
+            pub const fn get(&self, i: usize) -> u8 {
+                let size = core::mem::size_of::<$ty>();
+                self.0 .0[i / size].to_le_bytes()[i % size]
+            }
+        }
+
+        impl Bytes<Be<&[$ty]>> {
+            pub const fn len(&self) -> usize {
+                self.0 .0.len() * core::mem::size_of::<$ty>()
+            }
+
+            pub const fn get(&self, i: usize) -> u8 {
+                let size = core::mem::size_of::<$ty>();
+                self.0 .0[i / size].to_be_bytes()[i % size]
+            }
+        }
+
+        impl<const N: usize> Bytes<Le<[$ty; N]>> {
+            pub const fn len(&self) -> usize {
+                Bytes(Le(&self.0 .0 as &[$ty])).len()
+            }
+
+            pub const fn get(&self, i: usize) -> u8 {
+                Bytes(Le(&self.0 .0 as &[$ty])).get(i)
+            }
+        }
+
+        impl<const N: usize> Bytes<Be<[$ty; N]>> {
+            pub const fn len(&self) -> usize {
+                Bytes(Be(&self.0 .0 as &[$ty])).len()
+            }
+
+            pub const fn get(&self, i: usize) -> u8 {
+                Bytes(Be(&self.0 .0 as &[$ty])).get(i)
+            }
+        }
+
+        impl<const N: usize> Bytes<Le<&[$ty; N]>> {
+            pub const fn len(&self) -> usize {
+                Bytes(Le(self.0 .0 as &[$ty])).len()
+            }
+
+            pub const fn get(&self, i: usize) -> u8 {
+                Bytes(Le(self.0 .0 as &[$ty])).get(i)
+            }
+        }
+
+        impl<const N: usize> Bytes<Be<&[$ty; N]>> {
+            pub const fn len(&self) -> usize {
+                Bytes(Be(self.0 .0 as &[$ty])).len()
+            }
+
+            pub const fn get(&self, i: usize) -> u8 {
+                Bytes(Be(self.0 .0 as &[$ty])).get(i)
+            }
+        }
+    )+};
+}
+
+impl_bytes_for_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// An integer to be encoded as a LEB128 variable-length integer.
+pub struct Varint(pub u64);
+
+impl Bytes<Varint> {
+    pub const fn len(&self) -> usize {
+        let bits = 64 - self.0 .0.leading_zeros() as usize;
+        if bits == 0 {
+            1
+        } else {
+            bits.div_ceil(7)
+        }
+    }
+
+    pub const fn get(&self, i: usize) -> u8 {
+        let b = (self.0 .0 >> (7 * i)) as u8 & 0x7f;
+        if i + 1 < self.len() {
+            b | 0x80
+        } else {
+            b
+        }
+    }
+}
+
+/// A length to be encoded using the BER/DER definite form.
+///
+/// Lengths below 128 are encoded as a single byte, larger lengths are encoded
+/// as `0x80 | n` followed by `n` big-endian bytes.
+pub struct DerLen(pub usize);
+
+impl Bytes<DerLen> {
+    pub const fn len(&self) -> usize {
+        let n = self.0 .0;
+        if n < 0x80 {
+            1
+        } else {
+            1 + core::mem::size_of::<usize>() - n.leading_zeros() as usize / 8
+        }
+    }
+
+    pub const fn get(&self, i: usize) -> u8 {
+        let n = self.0 .0;
+        let len = self.len();
+        if n < 0x80 {
+            n as u8
+        } else if i == 0 {
+            0x80 | (len - 1) as u8
+        } else {
+            (n >> (8 * (len - 1 - i))) as u8
+        }
+    }
+}
+
+/// A CBOR initial byte and argument, encoded in the shortest form.
+pub struct CborHead(pub u8, pub u64);
+
+impl Bytes<CborHead> {
+    pub const fn len(&self) -> usize {
+        match self.0 .1 {
+            0..=23 => 1,
+            24..=0xff => 2,
+            0x100..=0xffff => 3,
+            0x1_0000..=0xffff_ffff => 5,
+            _ => 9,
+        }
+    }
+
+    pub const fn get(&self, i: usize) -> u8 {
+        let CborHead(major, n) = self.0;
+        let len = self.len();
+        if i > 0 {
+            (n >> (8 * (len - 1 - i))) as u8
+        } else {
+            let info = match len {
+                1 => n as u8,
+                2 => 24,
+                3 => 25,
+                5 => 26,
+                _ => 27,
+            };
+            major << 5 | info
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Str
+////////////////////////////////////////////////////////////////////////////////
+
+/// A value that can be converted to a string by `concat!`.
+///
+/// Like [`Bytes`] the conversion is selected by the type of the value using
+/// inherent impls.
+pub struct Str<T>(pub T);
+
+impl Str<&str> {
+    pub const fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub const fn get(&self, i: usize) -> u8 {
+        self.0.as_bytes()[i]
+    }
+}
+
+impl Str<char> {
+    pub const fn len(&self) -> usize {
+        self.0.len_utf8()
+    }
+
+    pub const fn get(&self, i: usize) -> u8 {
+        encode_utf8(self.0 as u32).0[i]
+    }
+}
+
+impl Str<bool> {
+    pub const fn len(&self) -> usize {
+        if self.0 {
+            4
+        } else {
+            5
+        }
+    }
+
+    pub const fn get(&self, i: usize) -> u8 {
+        if self.0 {
+            b"true"[i]
+        } else {
+            b"false"[i]
+        }
+    }
+}
+
+/// Returns the number of decimal digits in `n`.
+const fn decimal_len(n: u128) -> usize {
+    let mut len = 1;
+    let mut n = n / 10;
+    while n > 0 {
+        len += 1;
+        n /= 10;
+    }
+    len
+}
+
+/// Returns the `i`th byte of the decimal representation of the integer.
+const fn decimal_get(n: u128, neg: bool, i: usize) -> u8 {
+    if neg && i == 0 {
+        return b'-';
+    }
+    let i = i - neg as usize;
+    let exp = decimal_len(n) - 1 - i;
+    b'0' + (n / 10u128.pow(exp as u32) % 10) as u8
+}
+
+macro_rules! impl_str_for_int {
+    ($($ty:ty),+) => {$(
+        impl Str<$ty> {
+            pub const fn len(&self) -> usize {
+                decimal_len(self.0.unsigned_abs() as u128) + (self.0 < 0) as usize
+            }
+
+            pub const fn get(&self, i: usize) -> u8 {
+                decimal_get(self.0.unsigned_abs() as u128, self.0 < 0, i)
+            }
+        }
+    )+};
+}
+
+macro_rules! impl_str_for_uint {
+    ($($ty:ty),+) => {$(
+        impl Str<$ty> {
+            pub const fn len(&self) -> usize {
+                decimal_len(self.0 as u128)
+            }
+
+            pub const fn get(&self, i: usize) -> u8 {
+                decimal_get(self.0 as u128, false, i)
+            }
+        }
+    )+};
+}
+
+impl_str_for_uint!(u8, u16, u32, u64, u128, usize);
+impl_str_for_int!(i8, i16, i32, i64, i128, isize);
+
+////////////////////////////////////////////////////////////////////////////////
+// UTF-8
+////////////////////////////////////////////////////////////////////////////////
+
+/// Decodes the char starting at byte index `i` of the valid UTF-8 `bytes`.
+///
+/// Returns the Unicode scalar value and the index of the next char.
+pub const fn decode_utf8(bytes: &[u8], i: usize) -> (u32, usize) {
+    let b = bytes[i] as u32;
+    if b < 0x80 {
+        (b, i + 1)
+    } else if b < 0xe0 {
+        let c = ((b & 0x1f) << 6) | (bytes[i + 1] as u32 & 0x3f);
+        (c, i + 2)
+    } else if b < 0xf0 {
+        let c =
+            ((b & 0x0f) << 12) | ((bytes[i + 1] as u32 & 0x3f) << 6) | (bytes[i + 2] as u32 & 0x3f);
+        (c, i + 3)
+    } else {
+        let c = ((b & 0x07) << 18)
+            | ((bytes[i + 1] as u32 & 0x3f) << 12)
+            | ((bytes[i + 2] as u32 & 0x3f) << 6)
+            | (bytes[i + 3] as u32 & 0x3f);
+        (c, i + 4)
+    }
+}
+
+/// Encodes the Unicode scalar value as UTF-8.
+///
+/// Returns the bytes and the number of them that are used.
+pub const fn encode_utf8(c: u32) -> ([u8; 4], usize) {
+    if c < 0x80 {
+        ([c as u8, 0, 0, 0], 1)
+    } else if c < 0x800 {
+        ([0xc0 | (c >> 6) as u8, 0x80 | (c & 0x3f) as u8, 0, 0], 2)
+    } else if c < 0x10000 {
+        let b = [
+            0xe0 | (c >> 12) as u8,
+            0x80 | ((c >> 6) & 0x3f) as u8,
+            0x80 | (c & 0x3f) as u8,
+            0,
+        ];
+        (b, 3)
+    } else {
+        let b = [
+            0xf0 | (c >> 18) as u8,
+            0x80 | ((c >> 12) & 0x3f) as u8,
+            0x80 | ((c >> 6) & 0x3f) as u8,
+            0x80 | (c & 0x3f) as u8,
+        ];
+        (b, 4)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// UTF-16
+////////////////////////////////////////////////////////////////////////////////
+
+const fn push_utf16<const N: usize>(buf: &mut Buffer<u16, N>, c: u32) {
+    if c < 0x10000 {
+        buf.push(c as u16);
+    } else {
+        let c = c - 0x10000;
+        buf.push(0xd800 | (c >> 10) as u16);
+        buf.push(0xdc00 | (c & 0x3ff) as u16);
+    }
+}
+
+pub const fn encode_utf16<const N: usize>(s: &str) -> Buffer<u16, N> {
+    let bytes = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, next) = decode_utf8(bytes, i);
+        push_utf16(&mut buf, c);
+        i = next;
+    }
+    buf
+}
+
+pub const fn encode_wstr<const N: usize>(s: &str) -> Buffer<u16, N> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == 0 {
+            panic!("string contains an interior NUL");
+        }
+        i += 1;
+    }
+    let mut buf = encode_utf16(s);
+    buf.push(0);
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// UTF-32
+////////////////////////////////////////////////////////////////////////////////
+
+pub const fn encode_utf32<const N: usize>(s: &str) -> Buffer<u32, N> {
+    let bytes = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, next) = decode_utf8(bytes, i);
+        buf.push(c);
+        i = next;
+    }
+    buf
+}
+
+pub const fn chars<const N: usize>(s: &str) -> Buffer<char, N> {
+    let bytes = s.as_bytes();
+    let mut buf = Buffer::new('\0');
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, next) = decode_utf8(bytes, i);
+        // SAFETY: The value was decoded from a &str so it is a valid char.
+        buf.push(unsafe { char::from_u32_unchecked(c) });
+        i = next;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Strings
+////////////////////////////////////////////////////////////////////////////////
+
+pub const fn push_str<const N: usize>(buf: &mut Buffer<u8, N>, s: &str) {
+    buf.extend(s.as_bytes());
+}
+
+pub const fn concat_strs<const N: usize>(parts: &[&str]) -> Buffer<u8, N> {
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < parts.len() {
+        push_str(&mut buf, parts[i]);
+        i += 1;
+    }
+    buf
+}
+
+/// NUL-terminates each string and appends a final NUL.
+pub const fn multi_sz<const N: usize>(parts: &[&str]) -> Buffer<u8, N> {
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < parts.len() {
+        if parts[i].is_empty() {
+            panic!("empty string would terminate the list early");
+        }
+        nul_terminate_into(&mut buf, parts[i].as_bytes());
+        i += 1;
+    }
+    buf.push(0);
+    buf
+}
+
+/// Appends a NUL byte, checking that there are no interior NUL bytes.
+pub const fn nul_terminate<const N: usize>(bytes: &[u8]) -> Buffer<u8, N> {
+    let mut buf = Buffer::new(0);
+    nul_terminate_into(&mut buf, bytes);
+    buf
+}
+
+const fn nul_terminate_into<const N: usize>(buf: &mut Buffer<u8, N>, bytes: &[u8]) {
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == 0 {
+            panic!("bytes contain an interior NUL");
+        }
+        buf.push(bytes[i]);
+        i += 1;
+    }
+    buf.push(0);
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Paths
+////////////////////////////////////////////////////////////////////////////////
+
+const fn is_path_separator(b: u8) -> bool {
+    b == b'/' || (cfg!(windows) && b == b'\\')
+}
+
+/// Joins the path segments with the target's separator, converting any
+/// separators to the target's separator and collapsing runs of them.
+pub const fn normalize_path<const N: usize>(segments: &[&str]) -> Buffer<u8, N> {
+    let sep = if cfg!(windows) { b'\\' } else { b'/' };
+    let mut buf = Buffer::new(0);
+    let mut last_sep = false;
+    let mut s = 0;
+    while s < segments.len() {
+        let bytes = segments[s].as_bytes();
+        let mut i = 0;
+        // Keep the leading `\\` of UNC and device paths like `\\server\share`
+        // and `\\?\C:\` since collapsing it would make the path relative to
+        // the current drive.
+        if cfg!(windows)
+            && s == 0
+            && bytes.len() >= 2
+            && is_path_separator(bytes[0])
+            && is_path_separator(bytes[1])
+        {
+            buf.extend(&[sep, sep]);
+            last_sep = true;
+            i = 2;
+        }
+        if s > 0 && !last_sep {
+            buf.push(sep);
+            last_sep = true;
+        }
+        while i < bytes.len() {
+            if !is_path_separator(bytes[i]) {
+                buf.push(bytes[i]);
+                last_sep = false;
+            } else if !last_sep {
+                buf.push(sep);
+                last_sep = true;
+            }
+            i += 1;
+        }
+        s += 1;
+    }
+    buf
+}
+
+/// Returns the byte offsets of the boundaries between the given strings when
+/// they are concatenated.
+pub const fn str_offsets<const N: usize>(parts: &[&str]) -> [usize; N] {
+    let mut offsets = Buffer::new(0);
+    offsets.push(0);
+    let mut i = 0;
+    while i < parts.len() {
+        offsets.push(offsets.buf[i] + parts[i].len());
+        i += 1;
+    }
+    offsets.into_array()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Pack
+////////////////////////////////////////////////////////////////////////////////
+
+/// An argument to `pack!`.
+#[derive(Clone, Copy)]
+pub enum PackValue {
+    Unsigned(u128),
+    Signed(i128),
+    F32(f32),
+    F64(f64),
+    Bytes(&'static [u8]),
+}
+
+/// A value that can be converted to a [`PackValue`].
+///
+/// The conversion is selected by the type of the value using inherent impls,
+/// since trait methods can't be called in const contexts.
+pub struct Pack<T>(pub T);
+
+macro_rules! impl_pack_for_int {
+    ($variant:ident as $as:ty: $($ty:ty),+) => {$(
+        impl Pack<$ty> {
+            pub const fn value(self) -> PackValue {
+                PackValue::$variant(self.0 as $as)
+            }
+        }
+    )+};
+}
+
+impl_pack_for_int!(Unsigned as u128: u8, u16, u32, u64, u128, usize, bool);
+impl_pack_for_int!(Signed as i128: i8, i16, i32, i64, i128, isize);
+
+impl Pack<f32> {
+    pub const fn value(self) -> PackValue {
+        PackValue::F32(self.0)
+    }
+}
+
+impl Pack<f64> {
+    pub const fn value(self) -> PackValue {
+        PackValue::F64(self.0)
+    }
+}
+
+impl Pack<&'static [u8]> {
+    pub const fn value(self) -> PackValue {
+        PackValue::Bytes(self.0)
+    }
+}
+
+impl<const N: usize> Pack<&'static [u8; N]> {
+    pub const fn value(self) -> PackValue {
+        PackValue::Bytes(self.0)
+    }
+}
+
+impl Pack<&'static str> {
+    pub const fn value(self) -> PackValue {
+        PackValue::Bytes(self.0.as_bytes())
+    }
+}
+
+const fn push_uint<const N: usize>(
+    buf: &mut Buffer<u8, N>,
+    value: u128,
+    width: usize,
+    big_endian: bool,
+) {
+    let mut i = 0;
+    while i < width {
+        let shift = if big_endian { width - 1 - i } else { i };
+        buf.push((value >> (8 * shift)) as u8);
+        i += 1;
+    }
+}
+
+/// Returns the bits of the value if it fits in an integer of the given width
+/// and signedness.
+const fn pack_int(value: PackValue, width: usize, signed: bool) -> u128 {
+    let bits = 8 * width as u32;
+    let fits = match value {
+        PackValue::Unsigned(v) if signed => v < 1 << (bits - 1),
+        PackValue::Unsigned(v) => bits == 128 || v < 1 << bits,
+        PackValue::Signed(v) if signed => {
+            bits == 128 || (v >= -(1 << (bits - 1)) && v < 1 << (bits - 1))
+        }
+        PackValue::Signed(v) => v >= 0 && (bits == 128 || v < 1 << bits),
+        _ => panic!("pack: expected an integer argument"),
+    };
+    if !fits {
+        panic!("pack: integer argument is out of range for the format");
+    }
+    match value {
+        PackValue::Unsigned(v) => v,
+        PackValue::Signed(v) => v as u128,
+        _ => unreachable!(),
+    }
+}
+
+pub const fn pack<const N: usize>(fmt: &str, args: &[PackValue]) -> Buffer<u8, N> {
+    let fmt = fmt.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    let mut big_endian = cfg!(target_endian = "big");
+    if !fmt.is_empty() {
+        let order = match fmt[0] {
+            b'<' => Some(false),
+            b'>' | b'!' => Some(true),
+            b'=' | b'@' => Some(big_endian),
+            _ => None,
+        };
+        if let Some(order) = order {
+            big_endian = order;
+            i += 1;
+        }
+    }
+
+    let mut a = 0;
+    while i < fmt.len() {
+        if fmt[i].is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let mut count = 1;
+        if fmt[i].is_ascii_digit() {
+            count = 0;
+            while i < fmt.len() && fmt[i].is_ascii_digit() {
+                count = count * 10 + (fmt[i] - b'0') as usize;
+                i += 1;
+            }
+            if i == fmt.len() {
+                panic!("pack: repeat count given without format character");
+            }
+        }
+        let code = fmt[i];
+        i += 1;
+
+        if code == b'x' {
+            let mut j = 0;
+            while j < count {
+                buf.push(0);
+                j += 1;
+            }
+            continue;
+        }
+
+        if code == b's' || code == b'p' {
+            if a == args.len() {
+                panic!("pack: not enough arguments for the format");
+            }
+            let bytes = match args[a] {
+                PackValue::Bytes(b) => b,
+                _ => panic!("pack: expected a string or byte slice argument"),
+            };
+            a += 1;
+            let mut width = count;
+            if code == b'p' && count > 0 {
+                width -= 1;
+                let len = if bytes.len() < width {
+                    bytes.len()
+                } else {
+                    width
+                };
+                buf.push(if len > 255 { 255 } else { len as u8 });
+            }
+            let mut j = 0;
+            while j < width {
+                buf.push(if j < bytes.len() { bytes[j] } else { 0 });
+                j += 1;
+            }
+            continue;
+        }
+
+        let mut j = 0;
+        while j < count {
+            if a == args.len() {
+                panic!("pack: not enough arguments for the format");
+            }
+            let arg = args[a];
+            a += 1;
+            match code {
+                b'c' => match arg {
+                    PackValue::Bytes(b) if b.len() == 1 => buf.push(b[0]),
+                    PackValue::Unsigned(_) => buf.push(pack_int(arg, 1, false) as u8),
+                    _ => panic!("pack: expected a single byte argument"),
+                },
+                b'?' => match arg {
+                    PackValue::Unsigned(v) if v <= 1 => buf.push(v as u8),
+                    _ => panic!("pack: expected a bool argument"),
+                },
+                b'b' => push_uint(&mut buf, pack_int(arg, 1, true), 1, big_endian),
+                b'B' => push_uint(&mut buf, pack_int(arg, 1, false), 1, big_endian),
+                b'h' => push_uint(&mut buf, pack_int(arg, 2, true), 2, big_endian),
+                b'H' => push_uint(&mut buf, pack_int(arg, 2, false), 2, big_endian),
+                b'i' | b'l' => push_uint(&mut buf, pack_int(arg, 4, true), 4, big_endian),
+                b'I' | b'L' => push_uint(&mut buf, pack_int(arg, 4, false), 4, big_endian),
+                b'q' => push_uint(&mut buf, pack_int(arg, 8, true), 8, big_endian),
+                b'Q' => push_uint(&mut buf, pack_int(arg, 8, false), 8, big_endian),
+                b'f' => match arg {
+                    PackValue::F32(v) => push_uint(&mut buf, v.to_bits() as u128, 4, big_endian),
+                    PackValue::F64(v) => {
+                        push_uint(&mut buf, (v as f32).to_bits() as u128, 4, big_endian)
+                    }
+                    _ => panic!("pack: expected a float argument"),
+                },
+                b'd' => match arg {
+                    PackValue::F32(v) => {
+                        push_uint(&mut buf, (v as f64).to_bits() as u128, 8, big_endian)
+                    }
+                    PackValue::F64(v) => push_uint(&mut buf, v.to_bits() as u128, 8, big_endian),
+                    _ => panic!("pack: expected a float argument"),
+                },
+                _ => panic!("pack: invalid format character"),
+            }
+            j += 1;
+        }
+    }
+    if a != args.len() {
+        panic!("pack: too many arguments for the format");
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// DER
+////////////////////////////////////////////////////////////////////////////////
+
+/// An integer to be encoded as the minimal two's complement big-endian bytes.
+pub struct DerInt<T>(pub T);
+
+/// Returns the 136-bit two's complement big-endian bytes of the integer and
+/// the index of the first byte of its minimal encoding.
+const fn der_int(neg: bool, magnitude: u128) -> ([u8; 17], usize) {
+    let mut b = [if neg { 0xff } else { 0x00 }; 17];
+    let v = if neg {
+        magnitude.wrapping_neg()
+    } else {
+        magnitude
+    }
+    .to_be_bytes();
+    let mut i = 0;
+    while i < 16 {
+        b[i + 1] = v[i];
+        i += 1;
+    }
+    let mut i = 0;
+    while i < 16 && ((b[i] == 0 && b[i + 1] & 0x80 == 0) || (b[i] == 0xff && b[i + 1] & 0x80 != 0))
+    {
+        i += 1;
+    }
+    (b, i)
+}
+
+macro_rules! impl_der_int {
+    ($($ty:ty),+) => {$(
+        impl Bytes<DerInt<$ty>> {
+            const fn encode(&self) -> ([u8; 17], usize) {
+                let n = Int(self.0 .0);
+                der_int(n.is_negative(), n.magnitude())
+            }
+
+            pub const fn len(&self) -> usize {
+                17 - self.encode().1
+            }
+
+            pub const fn get(&self, i: usize) -> u8 {
+                let (b, start) = self.encode();
+                b[start + i]
+            }
+        }
+    )+};
+}
+
+impl_der_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Returns the identifier octet of a constructed context-specific tag.
+pub const fn der_context_tag(n: u8) -> u8 {
+    if n > 30 {
+        panic!("context-specific tag number must be less than 31");
+    }
+    0xa0 | n
+}
+
+/// Encodes a tag, the length of the contents, and the contents.
+pub const fn der_tlv<const N: usize>(tag: u8, contents: &[u8]) -> Buffer<u8, N> {
+    let mut buf = Buffer::new(0);
+    buf.push(tag);
+    let len = Bytes(DerLen(contents.len()));
+    let mut i = 0;
+    while i < len.len() {
+        buf.push(len.get(i));
+        i += 1;
+    }
+    let mut i = 0;
+    while i < contents.len() {
+        buf.push(contents[i]);
+        i += 1;
+    }
+    buf
+}
+
+const fn push_base128<const N: usize>(buf: &mut Buffer<u8, N>, v: u64) {
+    let mut shift = 63 / 7 * 7;
+    while shift > 0 && v >> shift == 0 {
+        shift -= 7;
+    }
+    while shift > 0 {
+        buf.push(0x80 | (v >> shift) as u8 & 0x7f);
+        shift -= 7;
+    }
+    buf.push(v as u8 & 0x7f);
+}
+
+/// Encodes the contents of an object identifier in dotted decimal notation.
+pub const fn encode_oid<const N: usize>(s: &str) -> Buffer<u8, N> {
+    let s = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    let mut arc = 0;
+    let mut first = 0;
+    while i <= s.len() {
+        let start = i;
+        let mut v: u64 = 0;
+        while i < s.len() && s[i] != b'.' {
+            if !s[i].is_ascii_digit() {
+                panic!("object identifier contains an invalid character");
+            }
+            v = match v.checked_mul(10) {
+                Some(v) => match v.checked_add((s[i] - b'0') as u64) {
+                    Some(v) => v,
+                    None => panic!("object identifier arc is too large"),
+                },
+                None => panic!("object identifier arc is too large"),
+            };
+            i += 1;
+        }
+        if i == start {
+            panic!("object identifier contains an empty arc");
+        }
+        match arc {
+            0 if v > 2 => panic!("object identifier must start with 0, 1, or 2"),
+            0 => first = v,
+            1 if first < 2 && v >= 40 => panic!("object identifier second arc must be below 40"),
+            1 => push_base128(&mut buf, first * 40 + v),
+            _ => push_base128(&mut buf, v),
+        }
+        arc += 1;
+        i += 1;
+    }
+    if arc < 2 {
+        panic!("object identifier must have at least two arcs");
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// CRC
+////////////////////////////////////////////////////////////////////////////////
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xedb88320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+};
+
+/// Computes the CRC-32/ISO-HDLC checksum of the bytes.
+pub const fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0;
+    let mut i = 0;
+    while i < bytes.len() {
+        crc = CRC32_TABLE[((crc ^ bytes[i] as u32) & 0xff) as usize] ^ (crc >> 8);
+        i += 1;
+    }
+    !crc
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// NMEA
+////////////////////////////////////////////////////////////////////////////////
+
+/// Wraps the comma separated fields of an NMEA 0183 sentence with the start
+/// delimiter, checksum, and line ending.
+pub const fn nmea<const N: usize>(body: &str) -> Buffer<u8, N> {
+    let body = body.as_bytes();
+    let mut buf = Buffer::new(0);
+    buf.push(b'$');
+    let mut checksum = 0;
+    let mut i = 0;
+    while i < body.len() {
+        if matches!(body[i], b'$' | b'!' | b'*' | b'\r' | b'\n') {
+            panic!("NMEA sentence field contains a reserved character");
+        }
+        checksum ^= body[i];
+        buf.push(body[i]);
+        i += 1;
+    }
+    buf.extend(&[
+        b'*',
+        HEX_UPPER[(checksum >> 4) as usize],
+        HEX_UPPER[(checksum & 0xf) as usize],
+        b'\r',
+        b'\n',
+    ]);
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// AT commands
+////////////////////////////////////////////////////////////////////////////////
+
+/// Terminates an AT command with exactly one `\r\n`.
+pub const fn at_command<const N: usize>(cmd: &[u8]) -> Buffer<u8, N> {
+    let mut end = cmd.len();
+    while end > 0 && matches!(cmd[end - 1], b'\r' | b'\n') {
+        end -= 1;
+    }
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < end {
+        if matches!(cmd[i], b'\r' | b'\n') {
+            panic!("AT command contains an interior line ending");
+        }
+        buf.push(cmd[i]);
+        i += 1;
+    }
+    buf.extend(b"\r\n");
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Padding
+////////////////////////////////////////////////////////////////////////////////
+
+/// Rounds the length up to the next multiple of `align`.
+pub const fn align_len(len: usize, align: usize) -> usize {
+    if align == 0 {
+        panic!("alignment must be greater than zero");
+    }
+    len.div_ceil(align) * align
+}
+
+/// Pads the bytes to the given length with the fill byte.
+pub const fn pad_bytes<const N: usize>(bytes: &[u8], len: usize, fill: u8) -> Buffer<u8, N> {
+    if bytes.len() > len {
+        panic!("content is longer than the padded length");
+    }
+    let mut buf = Buffer::new(fill);
+    let mut i = 0;
+    while i < len {
+        buf.push(if i < bytes.len() { bytes[i] } else { fill });
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Hex
+////////////////////////////////////////////////////////////////////////////////
+
+const HEX_LOWER: &[u8; 16] = b"0123456789abcdef";
+const HEX_UPPER: &[u8; 16] = b"0123456789ABCDEF";
+
+const fn hex_digit(b: u8) -> u8 {
+    match b {
+        b'0'..=b'9' => b - b'0',
+        b'a'..=b'f' => b - b'a' + 10,
+        b'A'..=b'F' => b - b'A' + 10,
+        _ => panic!("hex string contains an invalid digit"),
+    }
+}
+
+/// Decodes a hex string, ignoring ASCII whitespace.
+pub const fn decode_hex<const N: usize>(s: &str) -> Buffer<u8, N> {
+    let s = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut hi = None;
+    let mut i = 0;
+    while i < s.len() {
+        if !s[i].is_ascii_whitespace() {
+            let d = hex_digit(s[i]);
+            hi = match hi {
+                Some(h) => {
+                    buf.push(h << 4 | d);
+                    None
+                }
+                None => Some(d),
+            };
+        }
+        i += 1;
+    }
+    if hi.is_some() {
+        panic!("hex string has an odd number of digits");
+    }
+    buf
+}
+
+/// Encodes the bytes as a hex string.
+pub const fn encode_hex<const N: usize>(bytes: &[u8], upper: bool) -> Buffer<u8, N> {
+    let digits = if upper { HEX_UPPER } else { HEX_LOWER };
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < bytes.len() {
+        buf.push(digits[(bytes[i] >> 4) as usize]);
+        buf.push(digits[(bytes[i] & 0xf) as usize]);
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Base64
+////////////////////////////////////////////////////////////////////////////////
+
+const BASE64_STANDARD: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64_URL_SAFE: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encodes the bytes as Base64.
+pub const fn encode_base64<const N: usize>(
+    bytes: &[u8],
+    url_safe: bool,
+    pad: bool,
+) -> Buffer<u8, N> {
+    let alphabet = if url_safe {
+        BASE64_URL_SAFE
+    } else {
+        BASE64_STANDARD
+    };
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < bytes.len() {
+        let n = bytes.len() - i;
+        let b0 = bytes[i] as u32;
+        let b1 = if n > 1 { bytes[i + 1] as u32 } else { 0 };
+        let b2 = if n > 2 { bytes[i + 2] as u32 } else { 0 };
+        let v = b0 << 16 | b1 << 8 | b2;
+        let mut k = 0;
+        while k < 4 {
+            if k <= n {
+                buf.push(alphabet[(v >> (18 - 6 * k) & 0x3f) as usize]);
+            } else if pad {
+                buf.push(b'=');
+            }
+            k += 1;
+        }
+        i += 3;
+    }
+    buf
+}
+
+/// Inserts a newline after every `width` bytes and at the end, if non-empty.
+pub const fn wrap_lines<const N: usize>(s: &str, width: usize) -> Buffer<u8, N> {
+    let s = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < s.len() {
+        buf.push(s[i]);
+        i += 1;
+        if i % width == 0 || i == s.len() {
+            buf.push(b'\n');
+        }
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// RLE
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the length of the run of equal bytes starting at `i`, up to 128.
+const fn run_len(bytes: &[u8], i: usize) -> usize {
+    let mut j = i + 1;
+    while j < bytes.len() && j - i < 128 && bytes[j] == bytes[i] {
+        j += 1;
+    }
+    j - i
+}
+
+/// Encodes the bytes using the PackBits run-length encoding.
+pub const fn encode_rle<const N: usize>(bytes: &[u8]) -> Buffer<u8, N> {
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < bytes.len() {
+        let run = run_len(bytes, i);
+        if run >= 3 {
+            buf.push((1 - run as isize) as u8);
+            buf.push(bytes[i]);
+            i += run;
+            continue;
+        }
+        // Collect literal bytes until the next run worth encoding.
+        let mut end = i + run;
+        while end < bytes.len() && end - i < 128 {
+            let run = run_len(bytes, end);
+            if run >= 3 {
+                break;
+            }
+            end += run;
+        }
+        if end - i > 128 {
+            end = i + 128;
+        }
+        buf.push((end - i - 1) as u8);
+        while i < end {
+            buf.push(bytes[i]);
+            i += 1;
+        }
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Deflate
+////////////////////////////////////////////////////////////////////////////////
+
+/// Computes the Adler-32 checksum of the bytes.
+pub const fn adler32(bytes: &[u8]) -> u32 {
+    let mut a: u32 = 1;
+    let mut b: u32 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        a = (a + bytes[i] as u32) % 65521;
+        b = (b + a) % 65521;
+        i += 1;
+    }
+    b << 16 | a
+}
+
+const fn push_le<const N: usize>(buf: &mut Buffer<u8, N>, v: u32, width: usize) {
+    let mut i = 0;
+    while i < width {
+        buf.push((v >> (8 * i)) as u8);
+        i += 1;
+    }
+}
+
+/// Encodes the bytes as a deflate stream of stored blocks.
+const fn deflate_stored<const N: usize>(buf: &mut Buffer<u8, N>, bytes: &[u8]) {
+    let mut i = 0;
+    loop {
+        let len = if bytes.len() - i > 0xffff {
+            0xffff
+        } else {
+            bytes.len() - i
+        };
+        let last = i + len == bytes.len();
+        buf.push(last as u8);
+        push_le(buf, len as u32, 2);
+        push_le(buf, !len as u32, 2);
+        let mut k = 0;
+        while k < len {
+            buf.push(bytes[i + k]);
+            k += 1;
+        }
+        i += len;
+        if last {
+            return;
+        }
+    }
+}
+
+/// Wraps the bytes in a zlib container using stored deflate blocks.
+pub const fn zlib_stored<const N: usize>(bytes: &[u8]) -> Buffer<u8, N> {
+    let mut buf = Buffer::new(0);
+    buf.extend(&[0x78, 0x01]);
+    deflate_stored(&mut buf, bytes);
+    buf.extend(&adler32(bytes).to_be_bytes());
+    buf
+}
+
+/// Wraps the bytes in a gzip container using stored deflate blocks.
+pub const fn gzip_stored<const N: usize>(bytes: &[u8]) -> Buffer<u8, N> {
+    // ID1, ID2, CM = deflate, FLG, MTIME, XFL, OS = unknown
+    let header = [0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff];
+    let mut buf = Buffer::new(0);
+    buf.extend(&header);
+    deflate_stored(&mut buf, bytes);
+    push_le(&mut buf, crc32(bytes), 4);
+    push_le(&mut buf, bytes.len() as u32, 4);
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Bits
+////////////////////////////////////////////////////////////////////////////////
+
+/// Packs bits into bytes, the last byte is padded with zeros.
+pub const fn pack_bits<const N: usize>(bits: &[bool], lsb_first: bool) -> Buffer<u8, N> {
+    let mut buf = Buffer::new(0);
+    let mut byte = 0;
+    let mut i = 0;
+    while i < bits.len() {
+        if bits[i] {
+            let shift = if lsb_first { i % 8 } else { 7 - i % 8 };
+            byte |= 1 << shift;
+        }
+        i += 1;
+        if i % 8 == 0 {
+            buf.push(byte);
+            byte = 0;
+        }
+    }
+    if i % 8 != 0 {
+        buf.push(byte);
+    }
+    buf
+}
+
+/// Decodes a string of binary digits, most significant bit first, ignoring
+/// ASCII whitespace and underscores.
+pub const fn decode_bits<const N: usize>(s: &str, pad: bool) -> Buffer<u8, N> {
+    let s = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut byte = 0;
+    let mut n = 0;
+    let mut i = 0;
+    while i < s.len() {
+        match s[i] {
+            b'0' | b'1' => {
+                byte = byte << 1 | (s[i] - b'0');
+                n += 1;
+                if n == 8 {
+                    buf.push(byte);
+                    byte = 0;
+                    n = 0;
+                }
+            }
+            b'_' => {}
+            b if b.is_ascii_whitespace() => {}
+            _ => panic!("bit string contains an invalid digit"),
+        }
+        i += 1;
+    }
+    if n != 0 {
+        if !pad {
+            panic!("number of bits is not a multiple of 8");
+        }
+        buf.push(byte << (8 - n));
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Packets
+////////////////////////////////////////////////////////////////////////////////
+
+/// Adds the bytes as big-endian 16-bit words to the one's complement sum.
+const fn inet_sum(mut sum: u32, bytes: &[u8]) -> u32 {
+    let mut i = 0;
+    while i < bytes.len() {
+        let hi = bytes[i] as u32;
+        let lo = if i + 1 < bytes.len() {
+            bytes[i + 1] as u32
+        } else {
+            0
+        };
+        sum += hi << 8 | lo;
+        sum = (sum & 0xffff) + (sum >> 16);
+        i += 2;
+    }
+    sum
+}
+
+/// Computes the Internet checksum (RFC 1071) of the bytes.
+pub const fn inet_checksum(bytes: &[u8]) -> u16 {
+    !inet_sum(0, bytes) as u16
+}
+
+pub struct Ethernet {
+    pub dst: [u8; 6],
+    pub src: [u8; 6],
+}
+
+pub struct Ipv4 {
+    pub src: [u8; 4],
+    pub dst: [u8; 4],
+    pub ttl: u8,
+    pub id: u16,
+}
+
+pub struct Udp {
+    pub src_port: u16,
+    pub dst_port: u16,
+}
+
+/// Builds an optional Ethernet header, an IPv4 header, and a UDP header
+/// followed by the payload.
+pub const fn udp_packet<const N: usize>(
+    eth: Option<Ethernet>,
+    ip: Ipv4,
+    udp: Udp,
+    payload: &[u8],
+) -> Buffer<u8, N> {
+    let udp_len = 8 + payload.len();
+    let ip_len = 20 + udp_len;
+    if ip_len > 0xffff {
+        panic!("payload is too large for a UDP packet");
+    }
+
+    let mut buf = Buffer::new(0);
+    if let Some(eth) = eth {
+        buf.extend(&eth.dst);
+        buf.extend(&eth.src);
+        buf.extend(&[0x08, 0x00]);
+    }
+
+    let len = (ip_len as u16).to_be_bytes();
+    let id = ip.id.to_be_bytes();
+    let mut header = [
+        0x45, 0x00, len[0], len[1], id[0], id[1], 0x00, 0x00, ip.ttl, 17, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0,
+    ];
+    let mut i = 0;
+    while i < 4 {
+        header[12 + i] = ip.src[i];
+        header[16 + i] = ip.dst[i];
+        i += 1;
+    }
+    let checksum = inet_checksum(&header).to_be_bytes();
+    header[10] = checksum[0];
+    header[11] = checksum[1];
+    buf.extend(&header);
+
+    let len = (udp_len as u16).to_be_bytes();
+    let src = udp.src_port.to_be_bytes();
+    let dst = udp.dst_port.to_be_bytes();
+    let mut header = [src[0], src[1], dst[0], dst[1], len[0], len[1], 0, 0];
+    let mut sum = inet_sum(0, &ip.src);
+    sum = inet_sum(sum, &ip.dst);
+    sum = inet_sum(sum, &[0, 17, len[0], len[1]]);
+    sum = inet_sum(sum, &header);
+    sum = inet_sum(sum, payload);
+    let checksum = match !sum as u16 {
+        0 => 0xffff,
+        c => c,
+    }
+    .to_be_bytes();
+    header[6] = checksum[0];
+    header[7] = checksum[1];
+    buf.extend(&header);
+
+    buf.extend(payload);
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// DNS
+////////////////////////////////////////////////////////////////////////////////
+
+/// Encodes a dotted domain name as length-prefixed labels.
+pub const fn dns_name<const N: usize>(s: &str) -> Buffer<u8, N> {
+    let s = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    // The root domain can be written as "" or ".".
+    let end = if !s.is_empty() && s[s.len() - 1] == b'.' {
+        s.len() - 1
+    } else {
+        s.len()
+    };
+    let mut start = 0;
+    while start < end {
+        let mut i = start;
+        while i < end && s[i] != b'.' {
+            i += 1;
+        }
+        let len = i - start;
+        if len == 0 {
+            panic!("domain name contains an empty label");
+        }
+        if len > 63 {
+            panic!("domain name label is longer than 63 bytes");
+        }
+        buf.push(len as u8);
+        while start < i {
+            buf.push(s[start]);
+            start += 1;
+        }
+        start += 1;
+    }
+    if end > 0 && s[end - 1] == b'.' {
+        panic!("domain name contains an empty label");
+    }
+    buf.push(0);
+    if buf.len > 255 {
+        panic!("domain name is longer than 255 bytes");
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Protobuf
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the key of a protobuf field.
+pub const fn pb_key(field: u32, wire_type: u8) -> Varint {
+    if field == 0 || field >= 1 << 29 {
+        panic!("protobuf field number must be between 1 and 2^29 - 1");
+    }
+    Varint((field as u64) << 3 | wire_type as u64)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// CBOR
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the head of a CBOR integer with the given sign and magnitude.
+pub const fn cbor_int(neg: bool, magnitude: u128) -> CborHead {
+    let (major, n) = if neg {
+        (1, magnitude - 1)
+    } else {
+        (0, magnitude)
+    };
+    if n > u64::MAX as u128 {
+        panic!("integer does not fit in a CBOR integer");
+    }
+    CborHead(major, n as u64)
+}
+
+/// Returns the head of a CBOR unsigned integer with the given sign and
+/// magnitude.
+pub const fn cbor_uint(neg: bool, magnitude: u128) -> CborHead {
+    if neg {
+        panic!("CBOR unsigned integer must not be negative");
+    }
+    cbor_int(false, magnitude)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// UUID
+////////////////////////////////////////////////////////////////////////////////
+
+/// Parses a UUID in the canonical `8-4-4-4-12` form.
+///
+/// If `reverse` is set the bytes are returned in reverse order.
+pub const fn parse_uuid(s: &str, reverse: bool) -> [u8; 16] {
+    let s = s.as_bytes();
+    if s.len() != 36 {
+        panic!("UUID must be in the form xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx");
+    }
+    let mut arr = [0; 16];
+    let mut n = 0;
+    let mut i = 0;
+    while i < s.len() {
+        if matches!(i, 8 | 13 | 18 | 23) {
+            if s[i] != b'-' {
+                panic!("UUID must be in the form xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx");
+            }
+            i += 1;
+        } else {
+            let b = hex_digit(s[i]) << 4 | hex_digit(s[i + 1]);
+            arr[if reverse { 15 - n } else { n }] = b;
+            n += 1;
+            i += 2;
+        }
+    }
+    arr
+}
+
+/// Formats the bytes of a UUID in the canonical `8-4-4-4-12` form.
+pub const fn format_uuid(bytes: &[u8; 16], upper: bool) -> [u8; 36] {
+    let digits = if upper { HEX_UPPER } else { HEX_LOWER };
+    let mut arr = [b'-'; 36];
+    let mut n = 0;
+    let mut i = 0;
+    while n < bytes.len() {
+        if matches!(i, 8 | 13 | 18 | 23) {
+            i += 1;
+        }
+        arr[i] = digits[(bytes[n] >> 4) as usize];
+        arr[i + 1] = digits[(bytes[n] & 0xf) as usize];
+        n += 1;
+        i += 2;
+    }
+    arr
+}
+
+/// Returns the bytes of a UUID in the big-endian order of RFC 4122.
+///
+/// If `guid` is set the bytes are in the in-memory layout of a Windows `GUID`
+/// and the first three fields are swapped from little-endian.
+pub const fn uuid_order(b: &[u8; 16], guid: bool) -> [u8; 16] {
+    if !guid {
+        return *b;
+    }
+    [
+        b[3], b[2], b[1], b[0], b[5], b[4], b[7], b[6], b[8], b[9], b[10], b[11], b[12], b[13],
+        b[14], b[15],
+    ]
+}
+
+/// Formats the bytes of a UUID in the braced registry form used by Windows.
+pub const fn format_guid(bytes: &[u8; 16]) -> [u8; 38] {
+    let uuid = format_uuid(bytes, true);
+    let mut arr = [0; 38];
+    arr[0] = b'{';
+    arr[37] = b'}';
+    let mut i = 0;
+    while i < uuid.len() {
+        arr[i + 1] = uuid[i];
+        i += 1;
+    }
+    arr
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Archives
+////////////////////////////////////////////////////////////////////////////////
+
+/// A file or directory in an archive, directories have a trailing `/`.
+pub struct ArchiveEntry<'a> {
+    pub name: &'a str,
+    pub data: &'a [u8],
+    pub mode: Option<u32>,
+}
+
+impl ArchiveEntry<'_> {
+    const fn is_dir(&self) -> bool {
+        let name = self.name.as_bytes();
+        !name.is_empty() && name[name.len() - 1] == b'/'
+    }
+
+    const fn mode(&self) -> u32 {
+        match self.mode {
+            Some(mode) => mode,
+            None if self.is_dir() => 0o755,
+            None => 0o644,
+        }
+    }
+}
+
+/// Writes `v` as zero padded octal digits followed by a NUL into the field.
+const fn put_octal(mut header: [u8; 512], offset: usize, width: usize, v: u64) -> [u8; 512] {
+    if width < 23 && v >> (3 * (width - 1)) != 0 {
+        panic!("value does not fit in the tar header field");
+    }
+    let mut i = 0;
+    while i < width - 1 {
+        header[offset + i] = b'0' + (v >> (3 * (width - 2 - i)) & 0o7) as u8;
+        i += 1;
+    }
+    header[offset + width - 1] = 0;
+    header
+}
+
+const fn put_bytes(mut header: [u8; 512], offset: usize, bytes: &[u8]) -> [u8; 512] {
+    let mut i = 0;
+    while i < bytes.len() {
+        header[offset + i] = bytes[i];
+        i += 1;
+    }
+    header
+}
+
+/// Returns the ustar header for the entry.
+const fn tar_header(entry: &ArchiveEntry<'_>) -> [u8; 512] {
+    let name = entry.name.as_bytes();
+    let (prefix, name): (&[u8], &[u8]) = if name.len() <= 100 {
+        (&[], name)
+    } else {
+        let mut i = name.len() - 101;
+        loop {
+            if i > 155 || i >= name.len() {
+                panic!("tar entry name is too long");
+            }
+            if name[i] == b'/' && i > 0 {
+                break;
+            }
+            i += 1;
+        }
+        let (prefix, rest) = name.split_at(i);
+        (prefix, rest.split_at(1).1)
+    };
+    let size = if entry.is_dir() { 0 } else { entry.data.len() };
+    if entry.is_dir() && !entry.data.is_empty() {
+        panic!("directory entry has data");
+    }
+
+    let mut header = [0; 512];
+    header = put_bytes(header, 0, name);
+    header = put_octal(header, 100, 8, entry.mode() as u64);
+    header = put_octal(header, 108, 8, 0);
+    header = put_octal(header, 116, 8, 0);
+    header = put_octal(header, 124, 12, size as u64);
+    header = put_octal(header, 136, 12, 0);
+    header = put_bytes(header, 148, b"        ");
+    header[156] = if entry.is_dir() { b'5' } else { b'0' };
+    header = put_bytes(header, 257, b"ustar\x0000");
+    header = put_bytes(header, 345, prefix);
+
+    let mut sum = 0;
+    let mut i = 0;
+    while i < header.len() {
+        sum += header[i] as u64;
+        i += 1;
+    }
+    header = put_octal(header, 148, 7, sum);
+    header[155] = b' ';
+    header
+}
+
+/// Builds a ustar archive containing the entries.
+pub const fn tar<const N: usize>(entries: &[ArchiveEntry<'_>]) -> Buffer<u8, N> {
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < entries.len() {
+        buf.extend(&tar_header(&entries[i]));
+        buf.extend(entries[i].data);
+        while buf.len % 512 != 0 {
+            buf.push(0);
+        }
+        i += 1;
+    }
+    buf.extend(&[0; 1024]);
+    buf
+}
+
+/// Appends `v` as eight uppercase hex digits.
+const fn push_hex_u32<const N: usize>(buf: &mut Buffer<u8, N>, v: u32) {
+    let mut i = 0;
+    while i < 8 {
+        buf.push(HEX_UPPER[(v >> (4 * (7 - i)) & 0xf) as usize]);
+        i += 1;
+    }
+}
+
+/// Appends a newc header and name, padded to a multiple of four bytes.
+const fn push_cpio_header<const N: usize>(
+    buf: &mut Buffer<u8, N>,
+    ino: u32,
+    mode: u32,
+    nlink: u32,
+    size: u32,
+    name: &[u8],
+) {
+    buf.extend(b"070701");
+    push_hex_u32(buf, ino);
+    push_hex_u32(buf, mode);
+    push_hex_u32(buf, 0); // uid
+    push_hex_u32(buf, 0); // gid
+    push_hex_u32(buf, nlink);
+    push_hex_u32(buf, 0); // mtime
+    push_hex_u32(buf, size);
+    let mut i = 0;
+    while i < 4 {
+        push_hex_u32(buf, 0); // devmajor, devminor, rdevmajor, rdevminor
+        i += 1;
+    }
+    push_hex_u32(buf, name.len() as u32 + 1);
+    push_hex_u32(buf, 0); // check
+    buf.extend(name);
+    buf.push(0);
+    while buf.len % 4 != 0 {
+        buf.push(0);
+    }
+}
+
+/// Builds a newc cpio archive containing the entries.
+pub const fn cpio<const N: usize>(entries: &[ArchiveEntry<'_>]) -> Buffer<u8, N> {
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < entries.len() {
+        let entry = &entries[i];
+        let perm = entry.mode();
+        if perm > 0o7777 {
+            panic!("mode must only contain permission bits");
+        }
+        let mut name = entry.name.as_bytes();
+        let (mode, nlink) = if entry.is_dir() {
+            if !entry.data.is_empty() {
+                panic!("directory entry has data");
+            }
+            name = name.split_at(name.len() - 1).0;
+            (0o040000 | perm, 2)
+        } else {
+            (0o100000 | perm, 1)
+        };
+        if entry.data.len() > u32::MAX as usize {
+            panic!("cpio entry data is too large");
+        }
+        let size = entry.data.len() as u32;
+        push_cpio_header(&mut buf, i as u32 + 1, mode, nlink, size, name);
+        buf.extend(entry.data);
+        while buf.len % 4 != 0 {
+            buf.push(0);
+        }
+        i += 1;
+    }
+    push_cpio_header(&mut buf, 0, 0, 1, 0, b"TRAILER!!!");
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Bitmap
+////////////////////////////////////////////////////////////////////////////////
+
+/// Packs an ASCII-art bitmap, each row is padded to a whole number of bytes.
+pub const fn bitmap<const N: usize>(s: &str, width: usize, on: char, off: char) -> Buffer<u8, N> {
+    if !on.is_ascii_graphic() || !off.is_ascii_graphic() || on == off {
+        panic!("pixels must be distinct printable ASCII characters");
+    }
+    let (on, off) = (on as u8, off as u8);
+    let s = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut byte = 0;
+    let mut x = 0;
+    let mut i = 0;
+    while i <= s.len() {
+        let b = if i < s.len() { s[i] } else { b'\n' };
+        if b == b'\n' {
+            if x != 0 && x != width {
+                panic!("bitmap row does not match the width");
+            }
+            if x % 8 != 0 {
+                buf.push(byte);
+            }
+            byte = 0;
+            x = 0;
+        } else if b == on || b == off {
+            if b == on {
+                byte |= 0x80 >> (x % 8);
+            }
+            x += 1;
+            if x % 8 == 0 {
+                buf.push(byte);
+                byte = 0;
+            }
+        } else if !b.is_ascii_whitespace() {
+            panic!("bitmap contains an invalid pixel");
+        }
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Case conversion
+////////////////////////////////////////////////////////////////////////////////
+
+/// Converts the ASCII letters in the string to uppercase or lowercase.
+pub const fn ascii_case<const N: usize>(s: &str, upper: bool) -> Buffer<u8, N> {
+    let s = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < s.len() {
+        buf.push(if upper {
+            s[i].to_ascii_uppercase()
+        } else {
+            s[i].to_ascii_lowercase()
+        });
+        i += 1;
+    }
+    buf
+}
+
+/// Uppercases the first character of each word and lowercases the rest.
+///
+/// Words are separated by ASCII characters that are not alphanumeric or an
+/// apostrophe.
+pub const fn title_case<const N: usize>(s: &str) -> Buffer<u8, N> {
+    let s = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut in_word = false;
+    let mut i = 0;
+    while i < s.len() {
+        let b = s[i];
+        if b.is_ascii() && !b.is_ascii_alphanumeric() && b != b'\'' {
+            buf.push(b);
+            in_word = false;
+        } else if in_word {
+            buf.push(b.to_ascii_lowercase());
+        } else {
+            buf.push(b.to_ascii_uppercase());
+            in_word = true;
+        }
+        i += 1;
+    }
+    buf
+}
+
+/// A case style for `to_case!`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    Snake,
+    Screaming,
+    Kebab,
+    Camel,
+    Pascal,
+}
+
+/// Converts the words in the string to the given case style.
+///
+/// Words are separated by ASCII characters that are not alphanumeric, by a
+/// lowercase letter or digit followed by an uppercase letter, and before the
+/// last uppercase letter in a run of uppercase letters followed by a lowercase
+/// letter. Non-ASCII characters are treated as lowercase letters.
+pub const fn to_case<const N: usize>(s: &str, case: Case) -> Buffer<u8, N> {
+    let s = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut words = 0;
+    let mut in_word = false;
+    let mut i = 0;
+    while i < s.len() {
+        let b = s[i];
+        if b.is_ascii() && !b.is_ascii_alphanumeric() {
+            in_word = false;
+            i += 1;
+            continue;
+        }
+        let next_lower = i + 1 < s.len() && (!s[i + 1].is_ascii() || s[i + 1].is_ascii_lowercase());
+        let start =
+            !in_word || b.is_ascii_uppercase() && (!s[i - 1].is_ascii_uppercase() || next_lower);
+        if start && words > 0 {
+            match case {
+                Case::Snake | Case::Screaming => buf.push(b'_'),
+                Case::Kebab => buf.push(b'-'),
+                Case::Camel | Case::Pascal => {}
+            }
+        }
+        let upper = match case {
+            Case::Screaming => true,
+            Case::Camel => start && words > 0,
+            Case::Pascal => start,
+            Case::Snake | Case::Kebab => false,
+        };
+        buf.push(if upper {
+            b.to_ascii_uppercase()
+        } else {
+            b.to_ascii_lowercase()
+        });
+        if start {
+            words += 1;
+        }
+        in_word = true;
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Padding
+////////////////////////////////////////////////////////////////////////////////
+
+/// Where to insert the fill characters when padding a string.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Pad {
+    Left,
+    Right,
+    Center,
+}
+
+/// Returns the number of chars in the string.
+pub const fn char_count(s: &str) -> usize {
+    let s = s.as_bytes();
+    let mut n = 0;
+    let mut i = 0;
+    while i < s.len() {
+        if s[i] & 0xc0 != 0x80 {
+            n += 1;
+        }
+        i += 1;
+    }
+    n
+}
+
+const fn push_chars<const N: usize>(buf: &mut Buffer<u8, N>, c: char, n: usize) {
+    let (bytes, len) = encode_utf8(c as u32);
+    let mut i = 0;
+    while i < n {
+        let mut j = 0;
+        while j < len {
+            buf.push(bytes[j]);
+            j += 1;
+        }
+        i += 1;
+    }
+}
+
+/// Pads the string with the fill char until it is at least `width` chars.
+///
+/// When centering, any odd fill char is inserted on the right.
+pub const fn pad_str<const N: usize>(s: &str, width: usize, fill: char, pad: Pad) -> Buffer<u8, N> {
+    let n = width.saturating_sub(char_count(s));
+    let (left, right) = match pad {
+        Pad::Left => (n, 0),
+        Pad::Right => (0, n),
+        Pad::Center => (n / 2, n - n / 2),
+    };
+    let mut buf = Buffer::new(0);
+    push_chars(&mut buf, fill, left);
+    push_str(&mut buf, s);
+    push_chars(&mut buf, fill, right);
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Trimming
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns whether the Unicode scalar value has the `White_Space` property,
+/// like [`char::is_whitespace`].
+const fn is_whitespace(c: u32) -> bool {
+    matches!(
+        c,
+        0x09..=0x0d
+            | 0x20
+            | 0x85
+            | 0xa0
+            | 0x1680
+            | 0x2000..=0x200a
+            | 0x2028
+            | 0x2029
+            | 0x202f
+            | 0x205f
+            | 0x3000
+    )
+}
+
+/// Returns whether the Unicode scalar value should be trimmed.
+///
+/// If no chars are given then whitespace is trimmed.
+const fn is_trimmed(c: u32, chars: Option<&[char]>) -> bool {
+    match chars {
+        None => is_whitespace(c),
+        Some(chars) => {
+            let mut i = 0;
+            while i < chars.len() {
+                if chars[i] as u32 == c {
+                    return true;
+                }
+                i += 1;
+            }
+            false
+        }
+    }
+}
+
+/// Returns the string with the matching leading and/or trailing chars removed.
+pub const fn trim<const N: usize>(
+    s: &str,
+    chars: Option<&[char]>,
+    start: bool,
+    end: bool,
+) -> Buffer<u8, N> {
+    let bytes = s.as_bytes();
+    let mut lo = 0;
+    while start && lo < bytes.len() {
+        let (c, next) = decode_utf8(bytes, lo);
+        if !is_trimmed(c, chars) {
+            break;
+        }
+        lo = next;
+    }
+    let mut hi = bytes.len();
+    while end && hi > lo {
+        let mut i = hi - 1;
+        while bytes[i] & 0xc0 == 0x80 {
+            i -= 1;
+        }
+        if !is_trimmed(decode_utf8(bytes, i).0, chars) {
+            break;
+        }
+        hi = i;
+    }
+    let mut buf = Buffer::new(0);
+    while lo < hi {
+        buf.push(bytes[lo]);
+        lo += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Replacing
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns whether `bytes` contains `pat` starting at byte index `i`.
+const fn matches_at(bytes: &[u8], i: usize, pat: &[u8]) -> bool {
+    if i + pat.len() > bytes.len() {
+        return false;
+    }
+    let mut j = 0;
+    while j < pat.len() {
+        if bytes[i + j] != pat[j] {
+            return false;
+        }
+        j += 1;
+    }
+    true
+}
+
+/// Replaces all non-overlapping matches of `from` with `to`.
+///
+/// Like `str::replace` an empty pattern matches between every char.
+pub const fn replace<const N: usize>(s: &str, from: &str, to: &str) -> Buffer<u8, N> {
+    let bytes = s.as_bytes();
+    let pat = from.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < bytes.len() {
+        if pat.is_empty() {
+            push_str(&mut buf, to);
+            let next = decode_utf8(bytes, i).1;
+            while i < next {
+                buf.push(bytes[i]);
+                i += 1;
+            }
+        } else if matches_at(bytes, i, pat) {
+            push_str(&mut buf, to);
+            i += pat.len();
+        } else {
+            buf.push(bytes[i]);
+            i += 1;
+        }
+    }
+    if pat.is_empty() {
+        push_str(&mut buf, to);
+    }
+    buf
+}
+
+/// Removes the prefix or suffix from the string.
+///
+/// If the string does not have the affix then it is returned unchanged, or the
+/// evaluation panics if the affix is required.
+pub const fn strip<const N: usize>(
+    s: &str,
+    affix: &str,
+    suffix: bool,
+    required: bool,
+) -> Buffer<u8, N> {
+    let bytes = s.as_bytes();
+    let affix = affix.as_bytes();
+    let (mut lo, mut hi) = (0, bytes.len());
+    if affix.len() <= bytes.len() {
+        if !suffix && matches_at(bytes, 0, affix) {
+            lo = affix.len();
+        } else if suffix && matches_at(bytes, bytes.len() - affix.len(), affix) {
+            hi -= affix.len();
+        }
+    }
+    if required && hi - lo == bytes.len() && !affix.is_empty() {
+        if suffix {
+            panic!("string does not end with the suffix");
+        } else {
+            panic!("string does not start with the prefix");
+        }
+    }
+    let mut buf = Buffer::new(0);
+    while lo < hi {
+        buf.push(bytes[lo]);
+        lo += 1;
+    }
+    buf
+}
+
+/// Reverses the order of the chars in the string.
+pub const fn reverse_str<const N: usize>(s: &str) -> Buffer<u8, N> {
+    let bytes = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut hi = bytes.len();
+    while hi > 0 {
+        let mut lo = hi - 1;
+        while bytes[lo] & 0xc0 == 0x80 {
+            lo -= 1;
+        }
+        let mut i = lo;
+        while i < hi {
+            buf.push(bytes[i]);
+            i += 1;
+        }
+        hi = lo;
+    }
+    buf
+}
+
+/// Keeps at most `max` chars of the string.
+///
+/// If the string is truncated then the ellipsis is appended, and counts
+/// towards the maximum.
+pub const fn truncate_chars<const N: usize>(s: &str, max: usize, ellipsis: &str) -> Buffer<u8, N> {
+    if char_count(s) <= max {
+        let mut buf = Buffer::new(0);
+        push_str(&mut buf, s);
+        return buf;
+    }
+    let n = char_count(ellipsis);
+    if n > max {
+        panic!("ellipsis is longer than the maximum number of chars");
+    }
+    let bytes = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut chars = 0;
+    let mut i = 0;
+    loop {
+        if bytes[i] & 0xc0 != 0x80 {
+            if chars == max - n {
+                break;
+            }
+            chars += 1;
+        }
+        buf.push(bytes[i]);
+        i += 1;
+    }
+    push_str(&mut buf, ellipsis);
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Lines
+////////////////////////////////////////////////////////////////////////////////
+
+/// Inserts the prefix at the start of every non-empty line.
+pub const fn indent<const N: usize>(s: &str, prefix: &str) -> Buffer<u8, N> {
+    let bytes = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut start = true;
+    let mut i = 0;
+    while i < bytes.len() {
+        if start && bytes[i] != b'\n' && !matches_at(bytes, i, b"\r\n") {
+            push_str(&mut buf, prefix);
+        }
+        start = bytes[i] == b'\n';
+        buf.push(bytes[i]);
+        i += 1;
+    }
+    buf
+}
+
+/// Returns the number of leading spaces and tabs starting at byte index `i`.
+const fn indent_len(bytes: &[u8], i: usize) -> usize {
+    let mut n = 0;
+    while i + n < bytes.len() && matches!(bytes[i + n], b' ' | b'\t') {
+        n += 1;
+    }
+    n
+}
+
+/// Returns the byte index of the next line after byte index `i`.
+const fn next_line(bytes: &[u8], mut i: usize) -> usize {
+    while i < bytes.len() && bytes[i] != b'\n' {
+        i += 1;
+    }
+    i + 1
+}
+
+/// Removes the longest common leading whitespace from every line.
+///
+/// Lines that only contain spaces and tabs are ignored when finding the
+/// common whitespace and are emptied.
+pub const fn dedent<const N: usize>(s: &str) -> Buffer<u8, N> {
+    let bytes = s.as_bytes();
+
+    // Find the margin as the start and length of a line's leading whitespace.
+    let mut margin: Option<(usize, usize)> = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let n = indent_len(bytes, i);
+        let blank = i + n == bytes.len() || bytes[i + n] == b'\n';
+        if !blank {
+            margin = match margin {
+                None => Some((i, n)),
+                Some((m, len)) => {
+                    let mut j = 0;
+                    while j < len && j < n && bytes[m + j] == bytes[i + j] {
+                        j += 1;
+                    }
+                    Some((m, j))
+                }
+            };
+        }
+        i = next_line(bytes, i);
+    }
+    let margin = match margin {
+        Some((_, len)) => len,
+        None => 0,
+    };
+
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < bytes.len() {
+        let n = indent_len(bytes, i);
+        let end = next_line(bytes, i);
+        let mut j = if i + n == bytes.len() || bytes[i + n] == b'\n' {
+            i + n
+        } else {
+            i + margin
+        };
+        while j < end && j < bytes.len() {
+            buf.push(bytes[j]);
+            j += 1;
+        }
+        i = end;
+    }
+    buf
+}
+
+/// Wraps each line of the string so that it is at most `width` chars.
+///
+/// Words are separated by runs of spaces and tabs, which are collapsed into a
+/// single space. Lines keep their leading whitespace, which is repeated on the
+/// lines they are wrapped onto. Words longer than the width are not split.
+pub const fn wrap<const N: usize>(s: &str, width: usize) -> Buffer<u8, N> {
+    let bytes = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < bytes.len() {
+        let end = next_line(bytes, i);
+        let text_end = if end > bytes.len() {
+            bytes.len()
+        } else {
+            end - 1
+        };
+        let indent = indent_len(bytes, i);
+        let (prefix, _) = bytes.split_at(i + indent);
+        let (_, prefix) = prefix.split_at(i);
+        let mut col = 0;
+        let mut j = i + indent;
+        while j < text_end {
+            if matches!(bytes[j], b' ' | b'\t') {
+                j += 1;
+                continue;
+            }
+            let mut k = j;
+            let mut len = 0;
+            while k < text_end && !matches!(bytes[k], b' ' | b'\t') {
+                if bytes[k] & 0xc0 != 0x80 {
+                    len += 1;
+                }
+                k += 1;
+            }
+            if col > 0 && col + 1 + len > width {
+                buf.push(b'\n');
+                col = 0;
+            }
+            if col == 0 {
+                buf.extend(prefix);
+                col = indent;
+            } else {
+                buf.push(b' ');
+                col += 1;
+            }
+            while j < k {
+                buf.push(bytes[j]);
+                j += 1;
+            }
+            col += len;
+        }
+        if end <= bytes.len() {
+            buf.push(b'\n');
+        }
+        i = end;
+    }
+    buf
+}
+
+/// Replaces each run of ASCII whitespace with a single space or newline.
+///
+/// Runs at the start and end of the string are removed.
+pub const fn collapse_whitespace<const N: usize>(s: &str, single_line: bool) -> Buffer<u8, N> {
+    let bytes = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_whitespace() {
+            buf.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let mut newline = false;
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            newline |= bytes[i] == b'\n';
+            i += 1;
+        }
+        if start > 0 && i < bytes.len() {
+            buf.push(if newline && !single_line { b'\n' } else { b' ' });
+        }
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Splitting
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns the byte index of the first match of `pat` at or after `i`.
+const fn find(bytes: &[u8], mut i: usize, pat: &[u8]) -> Option<usize> {
+    while i + pat.len() <= bytes.len() {
+        if matches_at(bytes, i, pat) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Returns the number of pieces when splitting the string by the delimiter.
+pub const fn split_count(s: &str, delim: &str) -> usize {
+    let bytes = s.as_bytes();
+    let delim = delim.as_bytes();
+    if delim.is_empty() {
+        panic!("delimiter is empty");
+    }
+    let mut n = 1;
+    let mut i = 0;
+    while let Some(j) = find(bytes, i, delim) {
+        n += 1;
+        i = j + delim.len();
+    }
+    n
+}
+
+/// Splits the string by the delimiter into exactly `N` pieces.
+pub const fn split<'a, const N: usize>(s: &'a str, delim: &str) -> [&'a str; N] {
+    let d = delim.len();
+    let mut arr = [""; N];
+    let mut rest = s;
+    let mut n = 0;
+    while n + 1 < N {
+        match find(rest.as_bytes(), 0, delim.as_bytes()) {
+            Some(j) => {
+                let (piece, tail) = rest.split_at(j);
+                arr[n] = piece;
+                rest = tail.split_at(d).1;
+            }
+            None => panic!("invalid length"),
+        }
+        n += 1;
+    }
+    arr[n] = rest;
+    arr
+}
+
+/// Splits the string at the first occurrence of the delimiter.
+pub const fn split_once<'a>(s: &'a str, delim: &str) -> (&'a str, &'a str) {
+    match find(s.as_bytes(), 0, delim.as_bytes()) {
+        Some(i) => {
+            let (head, tail) = s.split_at(i);
+            (head, tail.split_at(delim.len()).1)
+        }
+        None => panic!("string does not contain the delimiter"),
+    }
+}
+
+/// Returns the next line of the string and the rest of the string.
+///
+/// Like `str::lines` the line ending is either `\n` or `\r\n`.
+const fn split_line(s: &str) -> (&str, &str) {
+    match find(s.as_bytes(), 0, b"\n") {
+        Some(i) => {
+            let (line, rest) = s.split_at(i);
+            let rest = rest.split_at(1).1;
+            match line.as_bytes() {
+                [.., b'\r'] => (line.split_at(line.len() - 1).0, rest),
+                _ => (line, rest),
+            }
+        }
+        None => (s, ""),
+    }
+}
+
+/// Splits the string into exactly `N` lines, optionally skipping empty lines.
+pub const fn lines<const N: usize>(s: &str, skip_empty: bool) -> ([&str; N], usize) {
+    let mut arr = [""; N];
+    let mut rest = s;
+    let mut n = 0;
+    while !rest.is_empty() {
+        let (line, tail) = split_line(rest);
+        if !(skip_empty && line.is_empty()) {
+            if n < N {
+                arr[n] = line;
+            }
+            n += 1;
+        }
+        rest = tail;
+    }
+    (arr, n)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// UTF-8 validation
+////////////////////////////////////////////////////////////////////////////////
+
+/// Writes the decimal representation of `n` into the buffer.
+const fn push_usize<const N: usize>(buf: &mut Buffer<u8, N>, n: usize) {
+    let mut div = 1;
+    while n / div >= 10 {
+        div *= 10;
+    }
+    while div > 0 {
+        buf.push(b'0' + (n / div % 10) as u8);
+        div /= 10;
+    }
+}
+
+/// Converts the bytes to a string, panicking with the offset of the first
+/// invalid byte if they are not valid UTF-8.
+pub const fn utf8(bytes: &[u8]) -> &str {
+    match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(err) => {
+            let mut buf: Buffer<u8, 64> = Buffer::new(0);
+            push_str(&mut buf, "invalid UTF-8 at byte offset ");
+            push_usize(&mut buf, err.valid_up_to());
+            let (msg, _) = buf.buf.split_at(buf.len);
+            // SAFETY: The message only contains ASCII.
+            panic!("{}", unsafe { core::str::from_utf8_unchecked(msg) })
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Escaping
+////////////////////////////////////////////////////////////////////////////////
+
+/// A set of escaping rules.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Escape {
+    C,
+    Json,
+    JsonAscii,
+    Html,
+}
+
+/// Escapes the string so that it can be placed between double quotes, or in
+/// the case of HTML in text or attribute values.
+pub const fn escape<const N: usize>(s: &str, rules: Escape) -> Buffer<u8, N> {
+    let bytes = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b >= 0x80 {
+            let (c, next) = decode_utf8(bytes, i);
+            if let Escape::JsonAscii = rules {
+                escape_json_char(&mut buf, c);
+            } else {
+                while i < next {
+                    buf.push(bytes[i]);
+                    i += 1;
+                }
+            }
+            i = next;
+            continue;
+        }
+        match rules {
+            Escape::C => escape_c(&mut buf, b),
+            Escape::Json | Escape::JsonAscii => escape_json(&mut buf, b),
+            Escape::Html => escape_html(&mut buf, b),
+        }
+        i += 1;
+    }
+    buf
+}
+
+const fn escape_c<const N: usize>(buf: &mut Buffer<u8, N>, b: u8) {
+    match b {
+        b'"' => buf.extend(b"\\\""),
+        b'\\' => buf.extend(b"\\\\"),
+        b'\n' => buf.extend(b"\\n"),
+        b'\r' => buf.extend(b"\\r"),
+        b'\t' => buf.extend(b"\\t"),
+        0x00..=0x1f | 0x7f => buf.extend(&[
+            b'\\',
+            b'0' + (b >> 6),
+            b'0' + (b >> 3 & 0o7),
+            b'0' + (b & 0o7),
+        ]),
+        _ => buf.push(b),
+    }
+}
+
+const fn escape_json<const N: usize>(buf: &mut Buffer<u8, N>, b: u8) {
+    match b {
+        b'"' => buf.extend(b"\\\""),
+        b'\\' => buf.extend(b"\\\\"),
+        0x08 => buf.extend(b"\\b"),
+        0x0c => buf.extend(b"\\f"),
+        b'\n' => buf.extend(b"\\n"),
+        b'\r' => buf.extend(b"\\r"),
+        b'\t' => buf.extend(b"\\t"),
+        0x00..=0x1f => escape_json_char(buf, b as u32),
+        _ => buf.push(b),
+    }
+}
+
+/// Escapes the Unicode scalar value as `\uXXXX`, using a surrogate pair if it
+/// is outside the Basic Multilingual Plane.
+const fn escape_json_char<const N: usize>(buf: &mut Buffer<u8, N>, c: u32) {
+    let (hi, lo) = if c >= 0x10000 {
+        let c = c - 0x10000;
+        (0xd800 | c >> 10, Some(0xdc00 | c & 0x3ff))
+    } else {
+        (c, None)
+    };
+    push_json_unit(buf, hi);
+    if let Some(lo) = lo {
+        push_json_unit(buf, lo);
+    }
+}
+
+const fn push_json_unit<const N: usize>(buf: &mut Buffer<u8, N>, u: u32) {
+    buf.extend(&[
+        b'\\',
+        b'u',
+        HEX_LOWER[(u >> 12 & 0xf) as usize],
+        HEX_LOWER[(u >> 8 & 0xf) as usize],
+        HEX_LOWER[(u >> 4 & 0xf) as usize],
+        HEX_LOWER[(u & 0xf) as usize],
+    ]);
+}
+
+const fn escape_html<const N: usize>(buf: &mut Buffer<u8, N>, b: u8) {
+    match b {
+        b'&' => push_str(buf, "&amp;"),
+        b'<' => push_str(buf, "&lt;"),
+        b'>' => push_str(buf, "&gt;"),
+        b'"' => push_str(buf, "&quot;"),
+        b'\'' => push_str(buf, "&#39;"),
+        _ => buf.push(b),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Percent-encoding
+////////////////////////////////////////////////////////////////////////////////
+
+/// A set of characters that are not percent-encoded.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum UrlSet {
+    Component,
+    Path,
+    Query,
+}
+
+/// Percent-encodes the bytes of the string that are not in the set.
+pub const fn urlencode<const N: usize>(s: &str, set: UrlSet) -> Buffer<u8, N> {
+    let bytes = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let keep = b.is_ascii_alphanumeric()
+            || matches!(b, b'-' | b'.' | b'_' | b'~')
+            || matches!(set, UrlSet::Path)
+                && matches!(
+                    b,
+                    b'/' | b':'
+                        | b'@'
+                        | b'!'
+                        | b'$'
+                        | b'&'
+                        | b'\''
+                        | b'('
+                        | b')'
+                        | b'*'
+                        | b'+'
+                        | b','
+                        | b';'
+                        | b'='
+                );
+        if keep {
+            buf.push(b);
+        } else if b == b' ' && matches!(set, UrlSet::Query) {
+            buf.push(b'+');
+        } else {
+            buf.extend(&[
+                b'%',
+                HEX_UPPER[(b >> 4) as usize],
+                HEX_UPPER[(b & 0xf) as usize],
+            ]);
+        }
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Slugs
+////////////////////////////////////////////////////////////////////////////////
+
+/// Converts the string to a lowercase slug, replacing each run of characters
+/// that are not ASCII alphanumeric with a single separator.
+pub const fn slugify<const N: usize>(s: &str, sep: char) -> Buffer<u8, N> {
+    if !sep.is_ascii() {
+        panic!("separator must be ASCII");
+    }
+    let s = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut pending = false;
+    let mut i = 0;
+    while i < s.len() {
+        let b = s[i];
+        if b.is_ascii_alphanumeric() {
+            if pending && buf.len > 0 {
+                buf.push(sep as u8);
+            }
+            buf.push(b.to_ascii_lowercase());
+            pending = false;
+        } else {
+            pending = true;
+        }
+        i += 1;
+    }
+    buf
+}
+
+/// Converts the string to a valid identifier, replacing each run of characters
+/// that are not ASCII alphanumeric or `_` with a single `_`.
+pub const fn to_ident<const N: usize>(s: &str) -> Buffer<u8, N> {
+    let s = s.as_bytes();
+    let mut buf = Buffer::new(0);
+    if s.is_empty() || s[0].is_ascii_digit() {
+        buf.push(b'_');
+    }
+    let mut i = 0;
+    while i < s.len() {
+        let b = s[i];
+        if b.is_ascii_alphanumeric() || b == b'_' {
+            buf.push(b);
+            i += 1;
+        } else {
+            buf.push(b'_');
+            while i < s.len() && !s[i].is_ascii_alphanumeric() && s[i] != b'_' {
+                i += 1;
+            }
+        }
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Assertions
+////////////////////////////////////////////////////////////////////////////////
+
+/// Returns whether the needle occurs anywhere in the haystack.
+pub const fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    find(haystack, 0, needle).is_some()
+}
+
+/// Returns whether the haystack starts with the needle.
+pub const fn starts_with(haystack: &[u8], needle: &[u8]) -> bool {
+    matches_at(haystack, 0, needle)
+}
+
+/// Returns whether the haystack ends with the needle.
+pub const fn ends_with(haystack: &[u8], needle: &[u8]) -> bool {
+    needle.len() <= haystack.len() && matches_at(haystack, haystack.len() - needle.len(), needle)
+}
+
+/// Returns whether the two byte slices are equal.
+pub const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && matches_at(a, 0, b)
+}
+
+/// Panics with the offset of the first byte that is not ASCII, or if
+/// `printable` is set the first byte that is not a printable ASCII character.
+pub const fn assert_ascii(bytes: &[u8], printable: bool, expr: &str) {
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let ok = if printable {
+            matches!(b, b' '..=b'~')
+        } else {
+            b.is_ascii()
+        };
+        if !ok {
+            let mut buf: Buffer<u8, 256> = Buffer::new(0);
+            push_str(&mut buf, "assertion failed: `");
+            push_str(&mut buf, expr);
+            push_str(
+                &mut buf,
+                if printable {
+                    "` contains a non-printable byte at offset "
+                } else {
+                    "` contains a non-ASCII byte at offset "
+                },
+            );
+            push_usize(&mut buf, i);
+            let (msg, _) = buf.buf.split_at(buf.len);
+            match core::str::from_utf8(msg) {
+                Ok(msg) => panic!("{}", msg),
+                Err(_) => panic!("assertion failed"),
+            }
+        }
+        i += 1;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Parsing
+////////////////////////////////////////////////////////////////////////////////
+
+/// Parses an integer in the given radix, panicking if it is invalid or not in
+/// the range `-min..=max`.
+///
+/// Like `str::parse` an optional leading `+` or `-` sign is allowed. Negative
+/// values are returned using their two's complement representation.
+pub const fn parse_int(s: &str, radix: u32, min: u128, max: u128) -> u128 {
+    if radix < 2 || radix > 36 {
+        panic!("radix must be in the range 2..=36");
+    }
+    let bytes = s.as_bytes();
+    let (neg, mut i) = match bytes {
+        [b'-', ..] => (true, 1),
+        [b'+', ..] => (false, 1),
+        _ => (false, 0),
+    };
+    if neg && min == 0 {
+        panic!("invalid digit found in string");
+    }
+    if i == bytes.len() {
+        if i == 0 {
+            panic!("cannot parse integer from empty string");
+        }
+        panic!("invalid digit found in string");
+    }
+    let mut n: u128 = 0;
+    while i < bytes.len() {
+        let d = match (bytes[i] as char).to_digit(radix) {
+            Some(d) => d,
+            None => panic!("invalid digit found in string"),
+        };
+        n = match n.checked_mul(radix as u128) {
+            Some(n) => match n.checked_add(d as u128) {
+                Some(n) => n,
+                None => panic!("number too large to fit in target type"),
+            },
+            None => panic!("number too large to fit in target type"),
+        };
+        i += 1;
+    }
+    if neg {
+        if n > min {
+            panic!("number too small to fit in target type");
+        }
+        n.wrapping_neg()
+    } else {
+        if n > max {
+            panic!("number too large to fit in target type");
+        }
+        n
+    }
+}
+
+/// Parses `true` or `false`.
+pub const fn parse_bool(s: &str) -> bool {
+    match s.as_bytes() {
+        b"true" => true,
+        b"false" => false,
+        _ => panic!("provided string was not `true` or `false`"),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Integer formatting
+////////////////////////////////////////////////////////////////////////////////
+
+/// An integer to be formatted as unsigned, negative values are formatted using
+/// their two's complement representation.
+pub struct Uint<T>(pub T);
+
+macro_rules! impl_uint {
+    ($($ty:ty => $uty:ty),+) => {$(
+        impl Uint<$ty> {
+            pub const fn get(&self) -> u128 {
+                self.0 as $uty as u128
+            }
+        }
+    )+};
+}
+
+impl_uint!(
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize,
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize
+);
+
+/// Formats the integer in a power of two radix with `bits` bits per digit,
+/// zero-padded to at least `width` digits.
+pub const fn fmt_radix<const N: usize>(
+    n: u128,
+    bits: u32,
+    width: usize,
+    prefix: bool,
+    upper: bool,
+) -> Buffer<u8, N> {
+    let digits = if upper { HEX_UPPER } else { HEX_LOWER };
+    let mut buf = Buffer::new(0);
+    if prefix {
+        buf.push(b'0');
+        buf.push(match bits {
+            1 => b'b',
+            3 => b'o',
+            _ => b'x',
+        });
+    }
+    let mut len = 1;
+    while len * (bits as usize) < 128 && n >> (len as u32 * bits) != 0 {
+        len += 1;
+    }
+    let mut i = len;
+    while i < width {
+        buf.push(b'0');
+        i += 1;
+    }
+    let mask = (1 << bits) - 1;
+    while len > 0 {
+        len -= 1;
+        buf.push(digits[(n >> (len as u32 * bits) & mask) as usize]);
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Number formatting
+////////////////////////////////////////////////////////////////////////////////
+
+/// Options for formatting a decimal number.
+#[derive(Clone, Copy)]
+pub struct NumFmt {
+    /// The minimum width of the output in chars.
+    pub width: usize,
+    /// Whether to pad with zeros after the sign instead of spaces before it.
+    pub zero: bool,
+    /// Whether to add a `+` sign to non-negative numbers.
+    pub sign: bool,
+    /// A separator to insert between groups of three integer digits.
+    pub sep: Option<char>,
+}
+
+impl NumFmt {
+    pub const DEFAULT: Self = Self {
+        width: 0,
+        zero: false,
+        sign: false,
+        sep: None,
+    };
+}
+
+/// An integer to be formatted in decimal by `fmt_int!`.
+pub struct Int<T>(pub T);
+
+macro_rules! impl_int {
+    ($($ty:ty),+) => {$(
+        impl Int<$ty> {
+            pub const fn is_negative(&self) -> bool {
+                self.0 < 0
+            }
+
+            pub const fn magnitude(&self) -> u128 {
+                self.0.unsigned_abs() as u128
+            }
+        }
+    )+};
+}
+
+macro_rules! impl_uint_int {
+    ($($ty:ty),+) => {$(
+        impl Int<$ty> {
+            pub const fn is_negative(&self) -> bool {
+                false
+            }
+
+            pub const fn magnitude(&self) -> u128 {
+                self.0 as u128
+            }
+        }
+    )+};
+}
+
+impl_uint_int!(u8, u16, u32, u64, u128, usize);
+impl_int!(i8, i16, i32, i64, i128, isize);
+
+/// Formats the integer in decimal.
+pub const fn fmt_int<const N: usize>(n: u128, neg: bool, f: NumFmt) -> Buffer<u8, N> {
+    let mut digits: Buffer<u8, 39> = Buffer::new(0);
+    let mut i = 0;
+    while i < decimal_len(n) {
+        digits.push(decimal_get(n, false, i));
+        i += 1;
+    }
+    let (body, _) = digits.buf.split_at(digits.len);
+    fmt_num(body, neg, f)
+}
+
+/// Formats the unsigned number `body` with a sign, separators, and padding.
+pub const fn fmt_num<const N: usize>(body: &[u8], neg: bool, f: NumFmt) -> Buffer<u8, N> {
+    let sign: &[u8] = if neg {
+        b"-"
+    } else if f.sign && !(body.len() == 3 && body[0] == b'N') {
+        b"+"
+    } else {
+        b""
+    };
+    let int_len = if !body.is_empty() && body[0].is_ascii_digit() {
+        match find(body, 0, b".") {
+            Some(i) => i,
+            None => body.len(),
+        }
+    } else {
+        0
+    };
+    let seps = match f.sep {
+        Some(_) if int_len > 0 => (int_len - 1) / 3,
+        _ => 0,
+    };
+    let sep_len = match f.sep {
+        Some(c) => c.len_utf8(),
+        None => 0,
+    };
+    let len = sign.len() + body.len() + seps * sep_len;
+    let pad = f.width.saturating_sub(sign.len() + body.len() + seps);
+
+    let mut buf = Buffer::new(0);
+    if !f.zero {
+        push_chars(&mut buf, ' ', pad);
+    }
+    let mut i = 0;
+    while i < sign.len() {
+        buf.push(sign[i]);
+        i += 1;
+    }
+    if f.zero {
+        push_chars(&mut buf, '0', pad);
+    }
+    let mut i = 0;
+    while i < body.len() {
+        if i > 0 && i < int_len && (int_len - i) % 3 == 0 {
+            if let Some(c) = f.sep {
+                push_chars(&mut buf, c, 1);
+            }
+        }
+        buf.push(body[i]);
+        i += 1;
+    }
+    if buf.len != len + pad {
+        panic!("invalid length");
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Format strings
+////////////////////////////////////////////////////////////////////////////////
+
+/// A piece of a format string.
+struct Piece {
+    /// The literal text before the argument.
+    text: (usize, usize),
+    /// The index of the argument to insert, if any.
+    arg: Option<usize>,
+    /// The byte index of the next piece.
+    next: usize,
+    /// The index of the next implicit positional argument.
+    pos: usize,
+}
+
+/// Parses the next piece of the format string starting at byte index `i`.
+///
+/// Returns `None` at the end of the string.
+const fn next_piece(fmt: &[u8], i: usize, pos: usize, names: &[&str]) -> Option<Piece> {
+    if i >= fmt.len() {
+        return None;
+    }
+    let start = i;
+    let mut i = i;
+    while i < fmt.len() && fmt[i] != b'{' && fmt[i] != b'}' {
+        i += 1;
+    }
+    if i > start {
+        return Some(Piece {
+            text: (start, i),
+            arg: None,
+            next: i,
+            pos,
+        });
+    }
+    if i + 1 < fmt.len() && fmt[i + 1] == fmt[i] {
+        return Some(Piece {
+            text: (i, i + 1),
+            arg: None,
+            next: i + 2,
+            pos,
+        });
+    }
+    if fmt[i] == b'}' {
+        panic!("{}", "invalid format string: unmatched `}` found");
+    }
+    let end = match find(fmt, i, b"}") {
+        Some(end) => end,
+        None => panic!(
+            "{}",
+            "invalid format string: expected `}` but string was terminated"
+        ),
+    };
+    let (_, rest) = fmt.split_at(i + 1);
+    let (inner, _) = rest.split_at(end - i - 1);
+    let (arg, pos) = if inner.is_empty() {
+        (pos, pos + 1)
+    } else if inner[0].is_ascii_digit() {
+        let mut n = 0;
+        let mut j = 0;
+        while j < inner.len() {
+            if !inner[j].is_ascii_digit() {
+                panic!("invalid format string: format specs are not supported");
+            }
+            n = n * 10 + (inner[j] - b'0') as usize;
+            j += 1;
+        }
+        (n, pos)
+    } else {
+        let mut j = 0;
+        loop {
+            if j == names.len() {
+                if find(inner, 0, b":").is_some() {
+                    panic!("invalid format string: format specs are not supported");
+                }
+                panic!("there is no argument with that name");
+            }
+            if bytes_eq(names[j].as_bytes(), inner) {
+                break;
+            }
+            j += 1;
+        }
+        (j, pos)
+    };
+    if arg >= names.len() {
+        panic!("invalid reference to positional argument");
+    }
+    Some(Piece {
+        text: (i, i),
+        arg: Some(arg),
+        next: end + 1,
+        pos,
+    })
+}
+
+/// Formats the arguments into the format string.
+///
+/// Each argument has a name, which is empty for positional arguments, and a
+/// value.
+pub const fn format<const N: usize>(fmt: &str, names: &[&str], args: &[&str]) -> Buffer<u8, N> {
+    let fmt = fmt.as_bytes();
+    let mut used = 0;
+    while used < names.len() {
+        let mut found = false;
+        let mut i = 0;
+        let mut pos = 0;
+        while let Some(p) = next_piece(fmt, i, pos, names) {
+            if let Some(arg) = p.arg {
+                found |= arg == used;
+            }
+            i = p.next;
+            pos = p.pos;
+        }
+        if !found {
+            panic!("argument never used");
+        }
+        used += 1;
+    }
+
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    let mut pos = 0;
+    while let Some(p) = next_piece(fmt, i, pos, names) {
+        let mut j = p.text.0;
+        while j < p.text.1 {
+            buf.push(fmt[j]);
+            j += 1;
+        }
+        if let Some(arg) = p.arg {
+            push_str(&mut buf, args[arg]);
+        }
+        i = p.next;
+        pos = p.pos;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Templates
+////////////////////////////////////////////////////////////////////////////////
+
+/// Replaces each `${NAME}` in the template with the value of the variable of
+/// the same name, and each `$$` with `$`.
+pub const fn template<const N: usize>(
+    tmpl: &str,
+    names: &[&str],
+    values: &[&str],
+) -> Buffer<u8, N> {
+    let bytes = tmpl.as_bytes();
+    let mut buf = Buffer::new(0);
+    let mut i = 0;
+    while i < bytes.len() {
+        if matches_at(bytes, i, b"$$") {
+            buf.push(b'$');
+            i += 2;
+        } else if matches_at(bytes, i, b"${") {
+            let end = match find(bytes, i + 2, b"}") {
+                Some(end) => end,
+                None => panic!("{}", "unterminated `${` in template"),
+            };
+            let (_, rest) = tmpl.split_at(i + 2);
+            let (name, _) = rest.split_at(end - i - 2);
+            let mut j = 0;
+            while j < names.len() && !bytes_eq(names[j].as_bytes(), name.as_bytes()) {
+                j += 1;
+            }
+            if j == names.len() {
+                let mut msg: Buffer<u8, 256> = Buffer::new(0);
+                push_str(&mut msg, "undefined template variable `");
+                push_str(&mut msg, name);
+                push_str(&mut msg, "`");
+                let len = if msg.len < 256 { msg.len } else { 256 };
+                match core::str::from_utf8(msg.buf.split_at(len).0) {
+                    Ok(msg) => panic!("{}", msg),
+                    Err(_) => panic!("undefined template variable"),
+                }
+            }
+            push_str(&mut buf, values[j]);
+            i = end + 1;
+        } else {
+            buf.push(bytes[i]);
+            i += 1;
+        }
+    }
+    buf
+}
@@ -0,0 +1,81 @@
+//! Run-length decoding of data produced by [`encode_rle!`].
+//!
+//! The encoding is the PackBits scheme. The data is a sequence of packets,
+//! each starting with a header byte `n` interpreted as an [`i8`].
+//!
+//! - `0..=127` is followed by `n + 1` literal bytes.
+//! - `-127..=-1` is followed by a single byte which is repeated `1 - n` times.
+//! - `-128` is ignored.
+//!
+//! [`encode_rle!`]: crate::encode_rle
+
+/// Returns the length of the decoded data.
+///
+/// # Panics
+///
+/// If the encoded data is truncated.
+pub const fn decoded_len(encoded: &[u8]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < encoded.len() {
+        let n = encoded[i] as i8;
+        i += 1;
+        if n >= 0 {
+            len += n as usize + 1;
+            i += n as usize + 1;
+        } else if n != -128 {
+            len += (1 - n as isize) as usize;
+            i += 1;
+        }
+    }
+    if i > encoded.len() {
+        panic!("run-length encoded data is truncated");
+    }
+    len
+}
+
+/// Decodes the data into the output buffer, returning the number of bytes
+/// written.
+///
+/// The output buffer should be at least [`decoded_len`] bytes long.
+///
+/// # Panics
+///
+/// If the encoded data is truncated or the output buffer is too small.
+///
+/// # Examples
+///
+/// ```
+/// use constcat::{encode_rle, rle};
+///
+/// const ENCODED: &[u8] = encode_rle!([0u8; 100], b"end");
+/// let mut framebuffer = [0xff; 103];
+/// assert_eq!(rle::decode_into(ENCODED, &mut framebuffer), 103);
+/// assert_eq!(&framebuffer[100..], b"end");
+/// ```
+pub const fn decode_into(encoded: &[u8], out: &mut [u8]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < encoded.len() {
+        let n = encoded[i] as i8;
+        i += 1;
+        if n >= 0 {
+            let mut k = 0;
+            while k <= n as usize {
+                out[len] = encoded[i];
+                len += 1;
+                i += 1;
+                k += 1;
+            }
+        } else if n != -128 {
+            let mut k = 0;
+            while k < (1 - n as isize) as usize {
+                out[len] = encoded[i];
+                len += 1;
+                k += 1;
+            }
+            i += 1;
+        }
+    }
+    len
+}
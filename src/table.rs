@@ -0,0 +1,113 @@
+/// A concatenated string along with the boundaries of the original segments.
+///
+/// This is returned by the [`concat_table!`] macro. The segments can be
+/// recovered by index using [`StrTable::get`].
+///
+/// [`concat_table!`]: crate::concat_table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StrTable {
+    blob: &'static str,
+    offsets: &'static [usize],
+}
+
+impl StrTable {
+    #[doc(hidden)]
+    pub const fn new(blob: &'static str, offsets: &'static [usize]) -> Self {
+        Self { blob, offsets }
+    }
+
+    /// Returns the entire concatenated string.
+    pub const fn as_str(&self) -> &'static str {
+        self.blob
+    }
+
+    /// Returns the byte offsets of the boundaries between the segments.
+    ///
+    /// This always contains one more element than the number of segments,
+    /// segment `i` spans the bytes from `offsets[i]` to `offsets[i + 1]`.
+    pub const fn offsets(&self) -> &'static [usize] {
+        self.offsets
+    }
+
+    /// Returns the number of segments.
+    pub const fn len(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Returns `true` if there are no segments.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the byte range of the segment at the given index.
+    pub const fn range(&self, index: usize) -> Option<(usize, usize)> {
+        if index < self.len() {
+            Some((self.offsets[index], self.offsets[index + 1]))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the segment at the given index.
+    pub const fn get(&self, index: usize) -> Option<&'static str> {
+        match self.range(index) {
+            Some((start, end)) => {
+                let (_, rest) = self.blob.as_bytes().split_at(start);
+                let (segment, _) = rest.split_at(end - start);
+                // SAFETY: The offsets are the boundaries between the original
+                // &str's so the segment is valid UTF-8.
+                Some(unsafe { core::str::from_utf8_unchecked(segment) })
+            }
+            None => None,
+        }
+    }
+}
+
+/// The location of a segment within a concatenated slice.
+///
+/// This is emitted by the [`concat_with_offsets!`] macro for each segment.
+///
+/// [`concat_with_offsets!`]: crate::concat_with_offsets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment {
+    name: &'static str,
+    offset: usize,
+    len: usize,
+}
+
+impl Segment {
+    #[doc(hidden)]
+    pub const fn new(name: &'static str, offset: usize, len: usize) -> Self {
+        Self { name, offset, len }
+    }
+
+    /// Returns the name of the segment.
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the offset of the start of the segment.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the length of the segment.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the segment has a length of zero.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the offset of the end of the segment.
+    pub const fn end(&self) -> usize {
+        self.offset + self.len
+    }
+
+    /// Returns the range of the segment.
+    pub const fn range(&self) -> core::ops::Range<usize> {
+        self.offset..self.end()
+    }
+}
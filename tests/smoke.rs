@@ -1,5 +1,3 @@
-#![cfg_attr(feature = "_bytes", feature(concat_bytes))]
-
 #[test]
 fn concat_smoke() {
     use constcat::concat;
@@ -27,12 +25,34 @@ fn concat_smoke() {
 
     const TEST7: &str = concat!("before ", env!("CARGO_PKG_NAME"), " after");
     assert_eq!(TEST7, "before constcat after");
+
+    const TEST8: &str = concat!(max_len = 4: "ab", 'c', 1,);
+    assert_eq!(TEST8, "abc1");
+
+    const MAX: usize = 0;
+    const TEST9: &str = concat!(max_len = MAX:);
+    assert_eq!(TEST9, "");
+
+    const TEST10: u16 = 8080;
+    const TEST11: &str = concat!("port ", TEST10, ' ', 0u8, 'é', true, false);
+    assert_eq!(TEST11, "port 8080 0étruefalse");
+
+    const TEST12: &str = concat!(i8::MIN, ",", i128::MIN, ",", u128::MAX, ",", -1isize);
+    assert_eq!(
+        TEST12,
+        "-128,-170141183460469231731687303715884105728,340282366920938463463374607431768211455,-1"
+    );
+
+    mod limits {
+        pub const MAX: usize = 3;
+    }
+    const TEST13: &str = concat!(max_len = limits::MAX: "ab", 'c');
+    assert_eq!(TEST13, "abc");
 }
 
 #[test]
-#[cfg(feature = "_bytes")]
 fn concat_bytes_smoke() {
-    use constcat::concat_bytes;
+    use constcat::{be, concat_bytes, le};
 
     const TEST0: &[u8] = concat_bytes!(b"test", b'b', &[68, b'E', 70]);
     assert_eq!(TEST0, b"testbDEF");
@@ -54,6 +74,59 @@ fn concat_bytes_smoke() {
 
     const TEST6: &[u8] = concat_bytes!(b"before ", TEST5, b" after");
     assert_eq!(TEST6, b"before one2 after");
+
+    const TEST7: [u8; 2] = [1, 2];
+    const TEST8: u8 = 3;
+    const TEST9: char = '🦀';
+    const TEST10: &[u8] = concat_bytes!(TEST7, TEST8, TEST9, 'a', &TEST7, [4], 5);
+    assert_eq!(TEST10, b"\x01\x02\x03\xf0\x9f\xa6\x80a\x01\x02\x04\x05");
+
+    const TEST11: &str = "name";
+    const TEST12: &[u8] = concat_bytes!(b"\x7fELF", TEST11, "", "🦀");
+    assert_eq!(TEST12, b"\x7fELFname\xf0\x9f\xa6\x80");
+
+    const TEST13: u32 = 0x01020304;
+    const TEST14: &[u8] = concat_bytes!(le(TEST13), be(TEST13), be(0x0506u16), le(-2i64),);
+    assert_eq!(
+        TEST14,
+        [4, 3, 2, 1, 1, 2, 3, 4, 5, 6, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+    );
+
+    const TEST15: &[u8] = concat_bytes!(b"v", le(u128::MAX), b'!', be(1usize));
+    assert_eq!(TEST15.len(), 2 + 16 + core::mem::size_of::<usize>());
+
+    const TEST16: &[u8] = concat_bytes!(prefix = le(u16): b"ab", TEST8, "", be(TEST13));
+    assert_eq!(TEST16, b"\x02\0ab\x01\0\x03\0\0\x04\0\x01\x02\x03\x04");
+
+    const TEST17: &[u8] = concat_bytes!(prefix = be(u32): b"xyz",);
+    assert_eq!(TEST17, b"\0\0\0\x03xyz");
+
+    const TEST18: &[u8] = concat_bytes!(prefix = be(u8):);
+    assert_eq!(TEST18, b"");
+
+    const TEST19: &[u16] = &[0x0102, 0x0304];
+    const TEST20: [i32; 1] = [-2];
+    const TEST21: &[u8] = concat_bytes!(le(TEST19), be(TEST19), le(&TEST20), be(&[0u64; 0]));
+    assert_eq!(TEST21, [2, 1, 4, 3, 1, 2, 3, 4, 0xfe, 0xff, 0xff, 0xff]);
+}
+
+#[test]
+fn concat_bytes_array_smoke() {
+    use constcat::concat_bytes;
+
+    const TEST0: &[u8; 0] = concat_bytes!(as [u8; 0]:);
+    assert_eq!(TEST0, &[]);
+
+    const TEST1: &[u8] = &[1, 2];
+    const TEST2: &[u8; 5] = concat_bytes!(as [u8; 5]: TEST1, &[3], TEST1,);
+    assert_eq!(TEST2, &[1, 2, 3, 1, 2]);
+
+    const N: usize = 2;
+    const TEST3: &[u8; N * 2] = concat_bytes!(as [u8; N * 2]: TEST1, TEST1);
+    assert_eq!(TEST3, &[1, 2, 1, 2]);
+
+    const TEST4: &[u8; 5] = concat_bytes!(as [u8; 5]: prefix = be(u8): b"ab", b'c');
+    assert_eq!(TEST4, b"\x02ab\x01c");
 }
 
 #[test]
@@ -119,6 +192,27 @@ fn concat_slices_smoke() {
     );
 }
 
+#[test]
+fn concat_slices_align_smoke() {
+    use constcat::concat_slices;
+
+    const TEST0: &[u8] = concat_slices!([u8, align = 64]:);
+    assert_eq!(TEST0, []);
+    assert_eq!(TEST0.as_ptr() as usize % 64, 0);
+
+    const TEST1: &[u8] = concat_slices!([u8, align = 4096]: &[1], &[2, 3],);
+    assert_eq!(TEST1, [1, 2, 3]);
+    assert_eq!(TEST1.as_ptr() as usize % 4096, 0);
+
+    const TEST2: &[u16] = concat_slices!([u16, align = 16]: &[1], &[2, 3]);
+    assert_eq!(TEST2, [1, 2, 3]);
+    assert_eq!(TEST2.as_ptr() as usize % 16, 0);
+
+    static TEST3: &[u8] = concat_slices!([u8, align = 32]: b"hello", b" world");
+    assert_eq!(TEST3, b"hello world");
+    assert_eq!(TEST3.as_ptr() as usize % 32, 0);
+}
+
 #[test]
 fn concat_namespacing() {
     use constcat::concat;
@@ -133,3 +227,2428 @@ fn concat_namespacing() {
     const TEST0: &str = concat!("test", 10, 'b', true);
     assert_eq!(TEST0, "test10btrue");
 }
+
+#[test]
+fn concat_utf16_smoke() {
+    use constcat::concat_utf16;
+
+    const TEST0: &[u16] = concat_utf16!();
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u16] = concat_utf16!("test", 10, 'b', true);
+    assert_eq!(TEST1, "test10btrue".encode_utf16().collect::<Vec<_>>());
+
+    const TEST2: &str = "aé€🦀";
+    const TEST3: &[u16] = concat_utf16!(TEST2, "!");
+    assert_eq!(TEST3, "aé€🦀!".encode_utf16().collect::<Vec<_>>());
+}
+
+#[test]
+fn wstr_smoke() {
+    use constcat::wstr;
+
+    const TEST0: &[u16] = wstr!();
+    assert_eq!(TEST0, [0]);
+
+    const TEST1: &[u16] = wstr!("one", 2);
+    assert_eq!(TEST1, [0x6f, 0x6e, 0x65, 0x32, 0]);
+
+    const TEST2: &[u16] = wstr!("🦀");
+    assert_eq!(TEST2, [0xd83e, 0xdd80, 0]);
+
+    const TEST3: *const u16 = wstr!("one").as_ptr();
+    assert_eq!(unsafe { *TEST3 }, 0x6f);
+}
+
+#[test]
+fn concat_utf32_smoke() {
+    use constcat::concat_utf32;
+
+    const TEST0: &[u32] = concat_utf32!();
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u32] = concat_utf32!("test", 10, 'b', true);
+    assert_eq!(
+        TEST1,
+        "test10btrue".chars().map(u32::from).collect::<Vec<_>>()
+    );
+
+    const TEST2: &str = "aé€🦀";
+    const TEST3: &[u32] = concat_utf32!(TEST2, "!");
+    assert_eq!(TEST3, [0x61, 0xe9, 0x20ac, 0x1f980, 0x21]);
+}
+
+#[test]
+fn chars_smoke() {
+    use constcat::{chars, concat_slices};
+
+    const TEST0: &[char] = chars!();
+    assert_eq!(TEST0, []);
+
+    const TEST1: &str = "a€😀";
+    const TEST2: &[char] = chars!(TEST1, 'b', 2);
+    assert_eq!(TEST2, ['a', '€', '😀', 'b', '2']);
+
+    const TEST3: &[char] = concat_slices!([char]: &['x'], chars!(TEST1));
+    assert_eq!(TEST3, ['x', 'a', '€', '😀']);
+}
+
+#[test]
+fn join_smoke() {
+    use constcat::join;
+
+    const TEST0: &str = join!(", ":);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = join!(", ":,);
+    assert_eq!(TEST1, "");
+
+    const TEST2: &str = join!(", ": "one");
+    assert_eq!(TEST2, "one");
+
+    const TEST3: &str = join!(", ": "one", 2, '3', true,);
+    assert_eq!(TEST3, "one, 2, 3, true");
+
+    const SEP: &str = "/";
+    const TEST4: &str = join!(SEP: TEST2, TEST3);
+    assert_eq!(TEST4, "one/one, 2, 3, true");
+
+    mod sep {
+        pub const COMMA: char = ',';
+    }
+    const TEST5: &str = join!(sep::COMMA: 1, 2);
+    assert_eq!(TEST5, "1,2");
+
+    const TEST6: &str = join!({ SEP.len() }: 'a', 'b');
+    assert_eq!(TEST6, "a1b");
+}
+
+#[test]
+fn join_slices_smoke() {
+    use constcat::join_slices;
+
+    const TEST0: &[i32] = join_slices!([i32] sep = &[0]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[i32] = join_slices!([i32] sep = &[0]:,);
+    assert_eq!(TEST1, []);
+
+    const TEST2: &[i32] = join_slices!([i32] sep = &[0]: &[1, 2]);
+    assert_eq!(TEST2, [1, 2]);
+
+    const SEP: &[i32] = &[-1, -1];
+    const TEST3: &[i32] = join_slices!([i32] sep = SEP: TEST2, &[3], TEST0, &[4],);
+    assert_eq!(TEST3, [1, 2, -1, -1, 3, -1, -1, -1, -1, 4]);
+
+    const TEST4: &[u8] = join_slices!([u8] sep = b", ": b"one", b"two");
+    assert_eq!(TEST4, b"one, two");
+
+    const TEST5: &[u8] = join_slices!([u8] sep = &[0xff, 0]: &[1], &[2]);
+    assert_eq!(TEST5, [1, 0xff, 0, 2]);
+
+    mod sep {
+        pub const COMMA: &[u8] = b",";
+    }
+    const TEST6: &[u8] = join_slices!([u8] sep = sep::COMMA: b"one", b"two");
+    assert_eq!(TEST6, b"one,two");
+
+    const TEST7: &[i32] =
+        join_slices!([i32] sep = { if SEP.is_empty() { &[1] } else { &[0] } }: &[1], &[2]);
+    assert_eq!(TEST7, [1, 0, 2]);
+}
+
+#[test]
+fn concat_lines_smoke() {
+    use constcat::concat_lines;
+
+    const TEST0: &str = concat_lines!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_lines!("one");
+    assert_eq!(TEST1, "one");
+
+    const TEST2: &str = concat_lines!("one", 2, TEST1,);
+    assert_eq!(TEST2, "one\n2\none");
+
+    const TEST3: &str = concat_lines!(crlf: "one", 2);
+    assert_eq!(TEST3, "one\r\n2");
+
+    const TEST4: &str = concat_lines!(trailing: "one", 2);
+    assert_eq!(TEST4, "one\n2\n");
+
+    const TEST5: &str = concat_lines!(crlf, trailing: "one", 2);
+    assert_eq!(TEST5, "one\r\n2\r\n");
+
+    const TEST6: &str = concat_lines!(trailing, crlf: TEST1);
+    assert_eq!(TEST6, "one\r\n");
+
+    const TEST7: &str = concat_lines!(trailing:);
+    assert_eq!(TEST7, "");
+}
+
+#[test]
+fn repeat_smoke() {
+    use constcat::repeat;
+
+    const TEST0: &str = repeat!("ab", 0);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = repeat!("", 3);
+    assert_eq!(TEST1, "");
+
+    const TEST2: &str = repeat!("ab", 3);
+    assert_eq!(TEST2, "ababab");
+
+    const TEST3: &str = repeat!('🦀', 2,);
+    assert_eq!(TEST3, "🦀🦀");
+
+    const N: usize = 2;
+    const TEST4: &str = repeat!(TEST2, N + 1);
+    assert_eq!(TEST4, "ababababababababab");
+
+    const TEST5: &str = repeat!(1, 4);
+    assert_eq!(TEST5, "1111");
+}
+
+#[test]
+fn repeat_slices_smoke() {
+    use constcat::repeat_slices;
+
+    const TEST0: &[i32] = repeat_slices!([i32]: &[1, 2], 0);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[i32] = repeat_slices!([i32]: TEST0, 3);
+    assert_eq!(TEST1, []);
+
+    const TEST2: &[i32] = repeat_slices!([i32]: &[1, 2], 3,);
+    assert_eq!(TEST2, [1, 2, 1, 2, 1, 2]);
+
+    const N: usize = 2;
+    const TEST3: &[i32] = repeat_slices!([i32]: TEST2, N);
+    assert_eq!(TEST3, [1, 2, 1, 2, 1, 2, 1, 2, 1, 2, 1, 2]);
+
+    const TEST4: &[(u8, char)] = repeat_slices!([(u8, char)]: &[(1, 'a')], 2);
+    assert_eq!(TEST4, [(1, 'a'), (1, 'a')]);
+
+    const S: &[u32] = &[0xffffffff];
+    const TEST5: &[u32] = repeat_slices!([u32]: S, 2);
+    assert_eq!(TEST5, [0xffffffff, 0xffffffff]);
+}
+
+#[test]
+fn intersperse_slices_smoke() {
+    use constcat::intersperse_slices;
+
+    const TEST0: &[i32] = intersperse_slices!([i32] with 0:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[i32] = intersperse_slices!([i32] with 0: &[1]);
+    assert_eq!(TEST1, [1]);
+
+    const TEST2: &[i32] = intersperse_slices!([i32] with 0: &[1, 2], TEST0, &[3],);
+    assert_eq!(TEST2, [1, 0, 2, 0, 3]);
+
+    const TEST3: &[i32] = intersperse_slices!([i32] with -1: TEST2);
+    assert_eq!(TEST3, [1, -1, 0, -1, 2, -1, 0, -1, 3]);
+
+    const X: char = '.';
+    const TEST4: &[char] = intersperse_slices!([char] with X: &['a', 'b'], &['c']);
+    assert_eq!(TEST4, ['a', '.', 'b', '.', 'c']);
+}
+
+#[test]
+fn flatten_smoke() {
+    use constcat::flatten;
+
+    const TEST0: &[i32] = flatten!([i32]: &[]);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[i32] = flatten!([i32]: &[&[], &[]]);
+    assert_eq!(TEST1, []);
+
+    const TEST2: &[&[i32]] = &[&[1, 2], TEST0, &[3]];
+    const TEST3: &[i32] = flatten!([i32]: TEST2,);
+    assert_eq!(TEST3, [1, 2, 3]);
+
+    const TEST4: &[&str] = flatten!([&str]: &[&["a"], &["b", "c"]]);
+    assert_eq!(TEST4, ["a", "b", "c"]);
+}
+
+#[test]
+fn concat_strs_smoke() {
+    use constcat::concat_strs;
+
+    const TEST0: &str = concat_strs!(&[]);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_strs!(&["one", "", "🦀"]);
+    assert_eq!(TEST1, "one🦀");
+
+    const TEST2: &[&str] = &[TEST1, " ", TEST1];
+    const TEST3: &str = concat_strs!(TEST2,);
+    assert_eq!(TEST3, "one🦀 one🦀");
+}
+
+#[test]
+#[cfg(not(windows))]
+fn concat_paths_smoke() {
+    use constcat::concat_paths;
+
+    const TEST0: &str = concat_paths!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_paths!("assets");
+    assert_eq!(TEST1, "assets");
+
+    const TEST2: &str = concat_paths!("/", TEST1, "sprites/", "/hero.png",);
+    assert_eq!(TEST2, "/assets/sprites/hero.png");
+
+    const TEST3: &str = concat_paths!("a//b", "", "c\\d/");
+    assert_eq!(TEST3, "a/b/c\\d/");
+}
+
+#[test]
+#[cfg(windows)]
+fn concat_paths_windows_smoke() {
+    use constcat::concat_paths;
+
+    const TEST0: &str = concat_paths!("a//b", "", "c/d\\");
+    assert_eq!(TEST0, "a\\b\\c\\d\\");
+
+    const TEST1: &str = concat_paths!("\\\\server", "share/", "file.txt");
+    assert_eq!(TEST1, "\\\\server\\share\\file.txt");
+
+    const TEST2: &str = concat_paths!("//?/C:", "dir");
+    assert_eq!(TEST2, "\\\\?\\C:\\dir");
+
+    const TEST3: &str = concat_paths!("\\", "C:", "dir");
+    assert_eq!(TEST3, "\\C:\\dir");
+}
+
+#[test]
+fn concat_include_smoke() {
+    use constcat::concat_include;
+
+    const TEST0: &str = concat_include!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_include!("data/a.sql", "data/b.sql",);
+    assert_eq!(TEST1, "CREATE TABLE a;\nCREATE TABLE b;\n");
+
+    const TEST2: &str = concat_include!(sep = "--\n": "data/a.sql", "data/b.sql");
+    assert_eq!(TEST2, "CREATE TABLE a;\n--\nCREATE TABLE b;\n");
+
+    mod sep {
+        pub const DASHES: &str = "--\n";
+    }
+    const TEST3: &str = concat_include!(sep = sep::DASHES: "data/a.sql", "data/b.sql");
+    assert_eq!(TEST3, TEST2);
+}
+
+#[test]
+fn concat_include_bytes_smoke() {
+    use constcat::concat_include_bytes;
+
+    const TEST0: &[u8] = concat_include_bytes!();
+    assert_eq!(TEST0, b"");
+
+    const TEST1: &[u8] = concat_include_bytes!("data/a.sql", "data/b.sql",);
+    assert_eq!(TEST1, b"CREATE TABLE a;\nCREATE TABLE b;\n");
+
+    const TEST2: &[u8] = concat_include_bytes!(sep = b"--\n": "data/a.sql", "data/b.sql");
+    assert_eq!(TEST2, b"CREATE TABLE a;\n--\nCREATE TABLE b;\n");
+
+    const TEST3: &[u8] = concat_include_bytes!(sep = &[0]: "data/a.sql", "data/b.sql");
+    assert_eq!(TEST3, b"CREATE TABLE a;\n\0CREATE TABLE b;\n");
+
+    mod sep {
+        pub const NUL: &[u8] = b"\0";
+    }
+    const TEST4: &[u8] = concat_include_bytes!(sep = sep::NUL: "data/a.sql", "data/b.sql");
+    assert_eq!(TEST4, TEST3);
+}
+
+#[test]
+#[cfg(feature = "glob")]
+fn concat_include_glob_smoke() {
+    use constcat::concat_include_glob;
+
+    const TEST0: &str = concat_include_glob!("tests/data/*.none");
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_include_glob!("tests/data/*.sql");
+    assert_eq!(TEST1, "CREATE TABLE a;\nCREATE TABLE b;\n");
+
+    const TEST2: &str = concat_include_glob!(sep = "--\n": "**/?.sql",);
+    assert_eq!(TEST2, "CREATE TABLE a;\n--\nCREATE TABLE b;\n");
+
+    mod sep {
+        pub const DASHES: &str = "--\n";
+    }
+    const TEST3: &str = concat_include_glob!(sep = sep::DASHES: "tests/data/*.sql");
+    assert_eq!(TEST3, TEST2);
+
+    const TEST4: &str = concat_include_glob!("tests/\x64ata/\u{61}.sql");
+    assert_eq!(TEST4, "CREATE TABLE a;\n");
+}
+
+#[test]
+#[cfg(feature = "glob")]
+fn concat_include_bytes_glob_smoke() {
+    use constcat::concat_include_bytes_glob;
+
+    const TEST0: &[u8] = concat_include_bytes_glob!("tests/data/*.none");
+    assert_eq!(TEST0, b"");
+
+    const TEST1: &[u8] = concat_include_bytes_glob!("./tests/data/b.*");
+    assert_eq!(TEST1, b"CREATE TABLE b;\n");
+
+    const TEST2: &[u8] = concat_include_bytes_glob!(sep = &[0]: "tests/*/*.sql");
+    assert_eq!(TEST2, b"CREATE TABLE a;\n\0CREATE TABLE b;\n");
+
+    mod sep {
+        pub const NUL: &[u8] = b"\0";
+    }
+    const TEST3: &[u8] = concat_include_bytes_glob!(sep = sep::NUL: "tests/data/*.sql");
+    assert_eq!(TEST3, TEST2);
+}
+
+#[test]
+fn concat_const_smoke() {
+    use constcat::concat_const;
+
+    concat_const! {}
+
+    concat_const! {
+        const TEST0: &str = ;
+        const TEST1: &'static str = "test", 10, 'b', true;
+        #[allow(dead_code)]
+        pub(crate) const TEST2: &str = TEST1, "!",;
+        const TEST3: &[u8] = b"one", &[2];
+        const TEST4: &[i32] = &[1, 2], &[3];
+        const TEST5: &[(u8, u8)] = &[(1, 2)];
+    }
+    assert_eq!(TEST0, "");
+    assert_eq!(TEST1, "test10btrue");
+    assert_eq!(TEST2, "test10btrue!");
+    assert_eq!(TEST3, b"one\x02");
+    assert_eq!(TEST4, [1, 2, 3]);
+    assert_eq!(TEST5, [(1, 2)]);
+
+    assert!(matches!("test10btrue", TEST1));
+
+    concat_const! {
+        /// Documentation
+        #[len(TEST7_LEN)]
+        #[allow(dead_code)]
+        pub const TEST7: &str = "one", 2;
+        #[len(TEST8_LEN)]
+        const TEST8: &[u8] = b"one";
+        #[len(TEST9_LEN)]
+        #[len(TEST9_SIZE)]
+        const TEST9: &[char] = &['a'], &['b'];
+    }
+    assert_eq!(TEST7_LEN, 4);
+    assert_eq!(TEST8_LEN, 3);
+    assert_eq!(TEST9_LEN, 2);
+    assert_eq!(TEST9_SIZE, 2);
+    const TEST10: [u8; TEST8_LEN] = [1; TEST8_LEN];
+    assert_eq!(TEST10, [1, 1, 1]);
+
+    concat_const! {
+        #[bytes(TEST11_BYTES)]
+        #[len(TEST11_LEN)]
+        const TEST11: &str = "one", 2;
+    }
+    assert_eq!(TEST11_BYTES, b"one2");
+    assert_eq!(TEST11_LEN, 4);
+}
+
+#[test]
+fn define_consts_smoke() {
+    use constcat::define_consts;
+
+    define_consts! {
+        prefix = "";
+    }
+
+    const PREFIX: &str = "app.";
+
+    define_consts! {
+        prefix = PREFIX;
+        TEST0 => "";
+        #[allow(dead_code)]
+        pub(crate) TEST1 => "requests", ".", "total";
+        TEST2 => 10, 'b', true,;
+    }
+    assert_eq!(TEST0, "app.");
+    assert_eq!(TEST1, "app.requests.total");
+    assert_eq!(TEST2, "app.10btrue");
+
+    define_consts! {
+        prefix = "metric_";
+        TEST3 => TEST2;
+    }
+    assert_eq!(TEST3, "metric_app.10btrue");
+}
+
+#[test]
+fn concat_static_smoke() {
+    use constcat::concat_static;
+
+    concat_static! {}
+
+    concat_static! {
+        static TEST0: [u8; _] = ;
+        #[used]
+        #[cfg_attr(target_os = "linux", link_section = ".rodata.constcat")]
+        static TEST1: [u8; _] = b"one", &[2];
+        #[allow(dead_code)]
+        pub(crate) static TEST2: [i32; _] = &[1, 2], &[3],;
+        static TEST3: [(u8, char); _] = &[(1, 'a')];
+    }
+    assert_eq!(TEST0, []);
+    assert_eq!(&TEST1, b"one\x02");
+    assert_eq!(TEST2, [1, 2, 3]);
+    assert_eq!(TEST3, [(1, 'a')]);
+}
+
+#[test]
+fn concat_table_smoke() {
+    use constcat::{concat_table, StrTable};
+
+    const TEST0: StrTable = concat_table!();
+    assert_eq!(TEST0.as_str(), "");
+    assert_eq!(TEST0.offsets(), [0]);
+    assert!(TEST0.is_empty());
+    assert_eq!(TEST0.get(0), None);
+
+    const TEST1: &str = "two";
+    const TEST2: StrTable = concat_table!("one", TEST1, "", '🦀', 5,);
+    assert_eq!(TEST2.as_str(), "onetwo🦀5");
+    assert_eq!(TEST2.offsets(), [0, 3, 6, 6, 10, 11]);
+    assert_eq!(TEST2.len(), 5);
+    assert_eq!(TEST2.range(1), Some((3, 6)));
+    assert_eq!(TEST2.get(0), Some("one"));
+    assert_eq!(TEST2.get(1), Some("two"));
+    assert_eq!(TEST2.get(2), Some(""));
+    assert_eq!(TEST2.get(3), Some("🦀"));
+    assert_eq!(TEST2.get(4), Some("5"));
+    assert_eq!(TEST2.get(5), None);
+
+    const TEST3: Option<&str> = TEST2.get(3);
+    assert_eq!(TEST3, Some("🦀"));
+}
+
+#[test]
+fn concat_with_offsets_smoke() {
+    use constcat::{concat_with_offsets, Segment};
+
+    concat_with_offsets! {
+        const TEST0: &str = {};
+    }
+    assert_eq!(TEST0, "");
+
+    concat_with_offsets! {
+        /// Documentation
+        const TEST1: &'static str = {
+            TEST1_A: "one",
+            #[allow(dead_code)]
+            TEST1_B: 2,
+            TEST1_C: "🦀",
+        };
+    }
+    assert_eq!(TEST1, "one2🦀");
+    assert_eq!((TEST1_A.offset(), TEST1_A.len()), (0, 3));
+    assert_eq!(TEST1_C.offset(), 4);
+    assert_eq!(&TEST1[TEST1_C.range()], "🦀");
+
+    concat_with_offsets! {
+        const TEST2: &[u8] = {
+            TEST2_A(TEST2_A_OFFSET, TEST2_A_LEN): b"one",
+            TEST2_B: b"",
+            TEST2_C(TEST2_C_OFFSET, TEST2_C_LEN): &[1, 2]
+        };
+    }
+    assert_eq!(TEST2, b"one\x01\x02");
+    assert_eq!((TEST2_A_OFFSET, TEST2_A_LEN), (0, 3));
+    assert_eq!((TEST2_C_OFFSET, TEST2_C_LEN), (3, 2));
+    assert_eq!((TEST2_A.offset(), TEST2_C.len()), (0, 2));
+    assert_eq!((TEST2_B.offset(), TEST2_B.len()), (3, 0));
+    assert!(TEST2_B.is_empty());
+    assert_eq!(TEST2_C.end(), 5);
+
+    concat_with_offsets! {
+        const TEST3: &[char] = {
+            TEST3_A: &['a'],
+            TEST3_B: &['b', 'c'],
+        };
+    }
+    assert_eq!(TEST3, ['a', 'b', 'c']);
+    const TEST4: Segment = TEST3_B;
+    assert_eq!(TEST3_A.range(), 0..1);
+    assert_eq!(&TEST3[TEST4.range()], ['b', 'c']);
+    assert_eq!(TEST4.name(), "TEST3_B");
+
+    concat_with_offsets! {
+        #[segments(TEST5_SEGMENTS)]
+        #[allow(dead_code)]
+        const TEST5: &str = {
+            TEST5_A: "one",
+            TEST5_B: 2,
+        };
+    }
+    assert_eq!(TEST5_SEGMENTS, [TEST5_A, TEST5_B]);
+    let names: Vec<_> = TEST5_SEGMENTS.iter().map(|s| s.name()).collect();
+    assert_eq!(names, ["TEST5_A", "TEST5_B"]);
+
+    concat_with_offsets! {
+        #[segments(TEST6_SEGMENTS)]
+        const TEST6: &[u8] = {};
+    }
+    assert_eq!(TEST6, b"");
+    assert_eq!(TEST6_SEGMENTS, []);
+}
+
+#[test]
+fn pack_smoke() {
+    use constcat::pack;
+
+    const TEST0: &[u8] = pack!("":);
+    assert_eq!(TEST0, b"");
+
+    const TEST1: &[u8] = pack!(">":,);
+    assert_eq!(TEST1, b"");
+
+    const TEST2: &[u8] = pack!("<bBhHiIqQ": -1i8, 2u8, -3i16, 4u16, -5i32, 6u32, -7i64, 8u64);
+    assert_eq!(
+        TEST2,
+        [
+            0xff, 2, 0xfd, 0xff, 4, 0, 0xfb, 0xff, 0xff, 0xff, 6, 0, 0, 0, 0xf9, 0xff, 0xff, 0xff,
+            0xff, 0xff, 0xff, 0xff, 8, 0, 0, 0, 0, 0, 0, 0
+        ]
+    );
+
+    const TEST3: &[u8] = pack!(">hHlL": -2i32, 0x0102u64, 3u8, 4usize);
+    assert_eq!(TEST3, [0xff, 0xfe, 1, 2, 0, 0, 0, 3, 0, 0, 0, 4]);
+
+    const FMT: &str = "! 2x c c ? 3s 4p";
+    const TEST4: &[u8] = pack!(FMT: b'a', "b", true, b"xyzw", "hello");
+    assert_eq!(TEST4, b"\0\0ab\x01xyz\x03hel");
+
+    const TEST5: &[u8] = pack!("<3B2H": 1u8, 2u8, 3u8, 4u16, 5u16,);
+    assert_eq!(TEST5, [1, 2, 3, 4, 0, 5, 0]);
+
+    const TEST6: &[u8] = pack!(">fd": 1.5f32, -2.0f64);
+    assert_eq!(TEST6, [0x3f, 0xc0, 0, 0, 0xc0, 0, 0, 0, 0, 0, 0, 0]);
+
+    const TEST7: &[u8] = pack!("I": 1u32);
+    assert_eq!(TEST7, 1u32.to_ne_bytes());
+}
+
+#[test]
+fn tlv_smoke() {
+    use constcat::tlv;
+
+    const TEST0: &[u8] = tlv!();
+    assert_eq!(TEST0, b"");
+
+    const TEST1: &[u8] = tlv!(tag = 0x01: b"ab", tag = 0x02: "", tag = 0x03: 'c',);
+    assert_eq!(TEST1, b"\x01\x02ab\x02\x00\x03\x01c");
+
+    const TAG: [u8; 2] = [0x5f, 0x20];
+    const TEST2: &[u8] = tlv!(tag = TAG: [0; 200]);
+    assert_eq!(&TEST2[..5], b"\x5f\x20\x81\xc8\0");
+    assert_eq!(TEST2.len(), 204);
+
+    const TEST3: &[u8] = tlv!(tag = 0x04: [1; 0x1234]);
+    assert_eq!(&TEST3[..5], b"\x04\x82\x12\x34\x01");
+    assert_eq!(TEST3.len(), 4 + 0x1234);
+}
+
+#[test]
+fn der_smoke() {
+    use constcat::der;
+
+    const TEST0: &[u8] = der!();
+    assert_eq!(TEST0, b"");
+
+    const TEST1: &[u8] = der!(BOOLEAN(true), BOOLEAN(false), NULL,);
+    assert_eq!(TEST1, b"\x01\x01\xff\x01\x01\x00\x05\x00");
+
+    const TEST2: &[u8] = der!(
+        INTEGER(0u8),
+        INTEGER(127i32),
+        INTEGER(128u16),
+        INTEGER(-129i16)
+    );
+    assert_eq!(
+        TEST2,
+        b"\x02\x01\x00\x02\x01\x7f\x02\x02\x00\x80\x02\x02\xff\x7f"
+    );
+
+    const TEST3: &[u8] = der!(INTEGER(u64::MAX), INTEGER(-1i8));
+    assert_eq!(
+        TEST3,
+        b"\x02\x09\x00\xff\xff\xff\xff\xff\xff\xff\xff\x02\x01\xff"
+    );
+
+    const TEST4: &[u8] = der!(OID("2.5.4.3"), OID("2.999.1"));
+    assert_eq!(TEST4, b"\x06\x03\x55\x04\x03\x06\x03\x88\x37\x01");
+
+    const NAME: &str = "example.com";
+    const TEST5: &[u8] = der!(SET {
+        SEQUENCE { OID("2.5.4.3"), UTF8_STRING(NAME) }
+    });
+    assert_eq!(
+        TEST5,
+        b"\x31\x14\x30\x12\x06\x03\x55\x04\x03\x0c\x0bexample.com"
+    );
+
+    const TEST6: &[u8] = der!(
+        [0] { INTEGER(2u8) },
+        BIT_STRING(b"\xaa"),
+        OCTET_STRING([1; 300]),
+        PRINTABLE_STRING("A"),
+        IA5_STRING('@'),
+        raw(TEST1),
+        SEQUENCE {},
+    );
+    assert_eq!(
+        &TEST6[..13],
+        b"\xa0\x03\x02\x01\x02\x03\x02\x00\xaa\x04\x82\x01\x2c"
+    );
+    assert_eq!(
+        &TEST6[313..],
+        b"\x13\x01A\x16\x01@\x01\x01\xff\x01\x01\x00\x05\x00\x30\x00"
+    );
+
+    const TEST7: &[u8] = der!(
+        INTEGER(u128::MAX),
+        INTEGER(1u128 << 127),
+        INTEGER(i128::MIN),
+        INTEGER(i8::MIN),
+        INTEGER(-128i16),
+        INTEGER(-32769i32),
+    );
+    assert_eq!(
+        TEST7,
+        b"\x02\x11\x00\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\xff\
+          \x02\x11\x00\x80\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+          \x02\x10\x80\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\
+          \x02\x01\x80\
+          \x02\x01\x80\
+          \x02\x03\xff\x7f\xff"
+    );
+}
+
+#[test]
+fn crc32_smoke() {
+    use constcat::{be, concat_bytes, crc32};
+
+    const TEST0: u32 = crc32!();
+    assert_eq!(TEST0, 0);
+
+    const TEST1: u32 = crc32!(b"123456789");
+    assert_eq!(TEST1, 0xcbf43926);
+
+    const TEST2: u32 = crc32!("The quick brown fox ", b"jumps over the lazy dog");
+    assert_eq!(TEST2, 0x414fa339);
+
+    const TEST3: &[u8] = concat_bytes!(append = le(crc32): b"1234", "56789");
+    assert_eq!(TEST3, b"123456789\x26\x39\xf4\xcb");
+
+    const TEST4: &[u8] = concat_bytes!(append = be(crc32): prefix = le(u8): b"ab");
+    assert_eq!(TEST4, concat_bytes!(b"\x02ab", be(crc32!(b"\x02ab"))));
+
+    const TEST5: &[u8; 4] = concat_bytes!(as [u8; 4]: append = le(crc32):);
+    assert_eq!(TEST5, &[0; 4]);
+}
+
+#[test]
+fn crc16_smoke() {
+    use constcat::{concat_bytes, crc16, Crc16};
+
+    const TEST0: u16 = crc16!(Crc16::MODBUS:);
+    assert_eq!(TEST0, 0xffff);
+
+    const TEST1: [u16; 6] = [
+        crc16!(Crc16::ARC: b"123456789"),
+        crc16!(Crc16::MODBUS: b"1234", "56789"),
+        crc16!(Crc16::IBM_3740: b"123456789"),
+        crc16!(Crc16::XMODEM: b"123456789"),
+        crc16!(Crc16::KERMIT: b"123456789"),
+        crc16!(Crc16::IBM_SDLC: b"123456789"),
+    ];
+    assert_eq!(TEST1, [0xbb3d, 0x4b37, 0x29b1, 0x31c3, 0x2189, 0x906e]);
+
+    const PARAMS: Crc16 = Crc16 {
+        poly: 0x1021,
+        init: 0xffff,
+        reflect_in: false,
+        reflect_out: false,
+        xor_out: 0xffff,
+    };
+    const TEST2: u16 = crc16!(PARAMS: b"123456789");
+    assert_eq!(TEST2, 0xd64e);
+
+    const TEST3: &[u8] = concat_bytes!(append = be(crc16(Crc16::XMODEM)): b"123456789");
+    assert_eq!(TEST3, b"123456789\x31\xc3");
+}
+
+#[test]
+fn modbus_rtu_smoke() {
+    use constcat::{be, modbus_rtu};
+
+    const TEST0: &[u8] = modbus_rtu!(0x01u8, 0x03u8, be(0u16), be(10u16));
+    assert_eq!(TEST0, [0x01, 0x03, 0x00, 0x00, 0x00, 0x0a, 0xc5, 0xcd]);
+
+    const TEST1: &[u8] = modbus_rtu!(b"\x11\x06\x00\x01\x00\x03",);
+    assert_eq!(TEST1, b"\x11\x06\x00\x01\x00\x03\x9a\x9b");
+}
+
+#[test]
+fn nmea_smoke() {
+    use constcat::nmea;
+
+    const TEST0: &str = nmea!("GPGLL");
+    assert_eq!(TEST0, "$GPGLL*50\r\n");
+
+    const TEST1: &str = nmea!("GPGLL", "", "", 12, "",);
+    assert_eq!(TEST1, "$GPGLL,,,12,*53\r\n");
+
+    const ID: &str = "GPGGA";
+    const TIME: &str = "123519";
+    const TEST2: &str = nmea!(
+        ID,
+        TIME,
+        "4807.038",
+        "N",
+        "01131.000",
+        "E",
+        1,
+        "08",
+        "0.9",
+        "545.4",
+        "M",
+        "46.9",
+        "M",
+        "",
+        ""
+    );
+    assert_eq!(
+        TEST2,
+        "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47\r\n"
+    );
+}
+
+#[test]
+fn at_smoke() {
+    use constcat::at;
+
+    const TEST0: &[u8] = at!();
+    assert_eq!(TEST0, b"\r\n");
+
+    const TEST1: &[u8] = at!(b"AT");
+    assert_eq!(TEST1, b"AT\r\n");
+
+    const TEST2: &[u8] = at!("AT", b"+CFUN=1", "\r\n\n\r",);
+    assert_eq!(TEST2, b"AT+CFUN=1\r\n");
+
+    const TEST3: &[u8] = at!(+CSQ);
+    assert_eq!(TEST3, b"AT+CSQ\r\n");
+
+    const TEST4: &[u8] = at!(+CREG?);
+    assert_eq!(TEST4, b"AT+CREG?\r\n");
+
+    const TEST5: &[u8] = at!(+CREG=?);
+    assert_eq!(TEST5, b"AT+CREG=?\r\n");
+
+    const MODE: &str = "2";
+    const TEST6: &[u8] = at!(+CREG = MODE);
+    assert_eq!(TEST6, b"AT+CREG=2\r\n");
+
+    const TEST7: &[u8] = at!(+CMGS = "\"+15551234567\"", 145,);
+    assert_eq!(TEST7, b"AT+CMGS=\"+15551234567\",145\r\n");
+}
+
+#[test]
+fn pad_to_smoke() {
+    use constcat::{le, pad_to};
+
+    const TEST0: &[u8; 0] = pad_to!(len = 0:);
+    assert_eq!(TEST0, b"");
+
+    const TEST1: &[u8; 4] = pad_to!(len = 4: b"ab", 'c', b'd');
+    assert_eq!(TEST1, b"abcd");
+
+    const N: usize = 3;
+    const TEST2: &[u8; N * 2] = pad_to!(len = N * 2, fill = 0xff: b"ab",);
+    assert_eq!(TEST2, b"ab\xff\xff\xff\xff");
+
+    const TEST3: &[u8] = pad_to!(align = 4:);
+    assert_eq!(TEST3, b"");
+
+    const TEST4: &[u8] = pad_to!(align = 4: b"abcd");
+    assert_eq!(TEST4, b"abcd");
+
+    const TEST5: &[u8] = pad_to!(align = N + 1, fill = b' ': "abcd", le(1u16));
+    assert_eq!(TEST5, b"abcd\x01\x00  ");
+}
+
+#[test]
+fn nul_terminated_smoke() {
+    use constcat::nul_terminated;
+
+    const TEST0: &[u8] = nul_terminated!();
+    assert_eq!(TEST0, b"\0");
+
+    const TEST1: &[u8] = nul_terminated!(b"one", "two", '3', b'4', [5],);
+    assert_eq!(TEST1, b"onetwo34\x05\0");
+
+    const TEST2: &str = "lib";
+    const TEST3: &[u8] = nul_terminated!(TEST2, b".so");
+    assert_eq!(TEST3, b"lib.so\0");
+}
+
+#[test]
+fn multi_sz_smoke() {
+    use constcat::{multi_sz, multi_sz_utf16};
+
+    const TEST0: &[u8] = multi_sz!();
+    assert_eq!(TEST0, b"\0");
+
+    const TEST1: &[u8] = multi_sz!("one", 2, "🦀",);
+    assert_eq!(TEST1, "one\x002\0🦀\0\0".as_bytes());
+
+    const TEST2: &[u16] = multi_sz_utf16!();
+    assert_eq!(TEST2, [0]);
+
+    const TEST3: &str = "ab";
+    const TEST4: &[u16] = multi_sz_utf16!(TEST3, "🦀");
+    assert_eq!(TEST4, [0x61, 0x62, 0, 0xd83e, 0xdd80, 0, 0]);
+}
+
+#[test]
+fn hex_smoke() {
+    use constcat::{concat_bytes, hex};
+
+    const TEST0: &[u8; 0] = hex!();
+    assert_eq!(TEST0, b"");
+
+    const TEST1: &[u8; 0] = hex!(" \n");
+    assert_eq!(TEST1, b"");
+
+    const TEST2: &[u8] = hex!("0123456789", "abcdef", "ABCDEF",);
+    assert_eq!(TEST2, b"\x01\x23\x45\x67\x89\xab\xcd\xef\xab\xcd\xef");
+
+    const TEST3: &str = "d e\ta\r\nd";
+    const TEST4: [u8; 2] = *hex!(TEST3);
+    assert_eq!(TEST4, [0xde, 0xad]);
+
+    const TEST5: &[u8] = concat_bytes!(hex!("00ff"), b'-', hex!(TEST3, "beef"));
+    assert_eq!(TEST5, b"\x00\xff-\xde\xad\xbe\xef");
+}
+
+#[test]
+fn encode_hex_smoke() {
+    use constcat::{be, encode_hex, hex};
+
+    const TEST0: &str = encode_hex!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = encode_hex!(b"\x00\x01\x7f\x80\xff", "A");
+    assert_eq!(TEST1, "00017f80ff41");
+
+    const TEST2: &str = encode_hex!(upper: b"\xab\xcd", be(0xef01u16),);
+    assert_eq!(TEST2, "ABCDEF01");
+
+    const TEST3: &str = encode_hex!(lower: hex!("ABCDEF"));
+    assert_eq!(TEST3, "abcdef");
+}
+
+#[test]
+fn encode_base64_smoke() {
+    use constcat::encode_base64;
+
+    const TEST0: &str = encode_base64!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: [&str; 7] = [
+        encode_base64!(b"f"),
+        encode_base64!(b"fo"),
+        encode_base64!(b"foo"),
+        encode_base64!(b"foob"),
+        encode_base64!(b"fooba"),
+        encode_base64!(b"foo", "bar"),
+        encode_base64!(b"\xfb\xff\xbf"),
+    ];
+    assert_eq!(
+        TEST1,
+        ["Zg==", "Zm8=", "Zm9v", "Zm9vYg==", "Zm9vYmE=", "Zm9vYmFy", "+/+/"]
+    );
+
+    const TEST2: [&str; 4] = [
+        encode_base64!(url: b"\xfb\xff"),
+        encode_base64!(nopad: b"\xfb\xff"),
+        encode_base64!(url, nopad: b"\xfb\xff\xbf", b'f'),
+        encode_base64!(nopad, url: b"f",),
+    ];
+    assert_eq!(TEST2, ["-_8=", "+/8", "-_-_Zg", "Zg"]);
+}
+
+#[test]
+fn pem_smoke() {
+    use constcat::pem;
+
+    const TEST0: &str = pem!("EMPTY":);
+    assert_eq!(TEST0, "-----BEGIN EMPTY-----\n-----END EMPTY-----\n");
+
+    const LABEL: &str = "PUBLIC KEY";
+    const TEST1: &str = pem!(LABEL: [0u8; 48], [0xffu8; 48], b"!");
+    assert_eq!(
+        TEST1,
+        "-----BEGIN PUBLIC KEY-----\n\
+         AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA\n\
+         ////////////////////////////////////////////////////////////////\n\
+         IQ==\n\
+         -----END PUBLIC KEY-----\n"
+    );
+}
+
+#[test]
+fn data_uri_smoke() {
+    use constcat::data_uri;
+
+    const TEST0: &str = data_uri!("text/plain":);
+    assert_eq!(TEST0, "data:text/plain;base64,");
+
+    const MIME: &str = "application/octet-stream";
+    const TEST1: &str = data_uri!(MIME: b"\x00\x01", 'a',);
+    assert_eq!(TEST1, "data:application/octet-stream;base64,AAFh");
+}
+
+#[test]
+fn rle_smoke() {
+    use constcat::{concat_bytes, decode_rle, encode_rle, rle};
+
+    const TEST0: &[u8] = encode_rle!();
+    assert_eq!(TEST0, b"");
+    assert_eq!(decode_rle!(TEST0), b"");
+
+    const TEST1: &[u8] = encode_rle!(b"abc");
+    assert_eq!(TEST1, b"\x02abc");
+
+    const TEST2: &[u8] = encode_rle!(b"aabbbbc", [0u8; 300]);
+    assert_eq!(TEST2, b"\x01aa\xfdb\x00c\x81\x00\x81\x00\xd5\x00");
+
+    const TEST3: [u8; 200] = {
+        let mut arr = [0; 200];
+        let mut i = 0;
+        while i < 200 {
+            arr[i] = i as u8;
+            i += 1;
+        }
+        arr
+    };
+    const TEST4: &[u8] = encode_rle!(TEST3, b"xxxxxxx");
+    assert_eq!(TEST4.len(), 1 + 128 + 1 + 72 + 2);
+    assert_eq!(decode_rle!(TEST4), concat_bytes!(TEST3, b"xxxxxxx"));
+
+    const TEST5: &[u8] = decode_rle!(TEST2);
+    assert_eq!(TEST5, concat_bytes!(b"aabbbbc", [0u8; 300]));
+
+    assert_eq!(rle::decoded_len(b"\x80\x00a"), 1);
+    let mut out = [0; 4];
+    assert_eq!(rle::decode_into(TEST1, &mut out), 3);
+    assert_eq!(out, *b"abc\0");
+}
+
+#[test]
+fn zlib_smoke() {
+    use constcat::zlib;
+
+    const TEST0: &[u8] = zlib!();
+    assert_eq!(TEST0, b"\x78\x01\x01\x00\x00\xff\xff\x00\x00\x00\x01");
+
+    const TEST1: &[u8] = zlib!(b"a", "bc",);
+    assert_eq!(TEST1, b"\x78\x01\x01\x03\x00\xfc\xffabc\x02\x4d\x01\x27");
+
+    const TEST2: &[u8] = zlib!([7u8; 70000]);
+    assert_eq!(TEST2.len(), 2 + 5 + 65535 + 5 + 4465 + 4);
+    assert_eq!(&TEST2[2..7], b"\x00\xff\xff\x00\x00");
+    assert_eq!(&TEST2[65542..65547], b"\x01\x71\x11\x8e\xee");
+}
+
+#[test]
+fn gzip_smoke() {
+    use constcat::gzip;
+
+    const TEST0: &[u8] = gzip!();
+    assert_eq!(
+        TEST0,
+        b"\x1f\x8b\x08\x00\x00\x00\x00\x00\x00\xff\x01\x00\x00\xff\xff\x00\x00\x00\x00\x00\x00\x00\x00"
+    );
+
+    const TEST1: &[u8] = gzip!(b"123456789");
+    assert_eq!(&TEST1[10..24], b"\x01\x09\x00\xf6\xff123456789");
+    assert_eq!(&TEST1[24..], b"\x26\x39\xf4\xcb\x09\x00\x00\x00");
+}
+
+#[test]
+fn swap_bytes_smoke() {
+    use constcat::swap_bytes;
+
+    const TEST0: &[u32] = swap_bytes!([u32]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u32] = swap_bytes!([u32]: &[0x01020304, 0xaabbccdd],);
+    assert_eq!(TEST1, [0x04030201, 0xddccbbaa]);
+
+    const TEST2: &[u16] = &[0x1234];
+    const TEST3: &[u16] = swap_bytes!([u16]: TEST2, TEST2, &[0xff00]);
+    assert_eq!(TEST3, [0x3412, 0x3412, 0x00ff]);
+
+    const TEST4: &[i64] = swap_bytes!([i64]: &[-2]);
+    assert_eq!(TEST4, [(-2i64).swap_bytes()]);
+
+    const TEST5: &[u8] = swap_bytes!([u8]: b"ab");
+    assert_eq!(TEST5, b"ab");
+}
+
+#[test]
+fn pack_bits_smoke() {
+    use constcat::pack_bits;
+
+    const TEST0: &[u8] = pack_bits!();
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = pack_bits!(lsb:);
+    assert_eq!(TEST1, []);
+
+    const T: bool = true;
+    const F: bool = false;
+    const TEST2: &[u8] = pack_bits!(&[T, F, F, F, F, F, F, T], &[F, T],);
+    assert_eq!(TEST2, [0x81, 0x40]);
+
+    const TEST3: &[u8] = pack_bits!(msb: &[F, T]);
+    assert_eq!(TEST3, [0x40]);
+
+    const TEST4: &[bool] = &[T, T, F, F, F, F, F, F, F, T];
+    const TEST5: &[u8] = pack_bits!(lsb: TEST4, TEST4);
+    assert_eq!(TEST5, [0x03, 0x0e, 0x08]);
+}
+
+#[test]
+fn bits_smoke() {
+    use constcat::{bits, concat_bytes};
+
+    const TEST0: &[u8] = bits!();
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = bits!(pad:);
+    assert_eq!(TEST1, []);
+
+    const TEST2: &[u8] = bits!("0110_1000 1110\t0001", "\n11111111",);
+    assert_eq!(TEST2, [0x68, 0xe1, 0xff]);
+
+    const HIGH: &str = "1111";
+    const LOW: &str = "0000";
+    const TEST3: &[u8] = bits!(HIGH, LOW, LOW, HIGH);
+    assert_eq!(TEST3, [0xf0, 0x0f]);
+
+    const TEST4: &[u8] = bits!(pad: HIGH, LOW, "1");
+    assert_eq!(TEST4, [0xf0, 0x80]);
+
+    const TEST5: &[u8] = concat_bytes!(b'a', bits!("01100010"), bits!(pad: "0110001"));
+    assert_eq!(TEST5, b"abb");
+}
+
+#[test]
+fn cast_slice_smoke() {
+    use constcat::cast_slice;
+
+    const TEST0: &[u32] = cast_slice!([u8] as [u32]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u16] = cast_slice!([u8] as [u16]: b"ab", &[1], &[2],);
+    assert_eq!(
+        TEST1,
+        [u16::from_ne_bytes(*b"ab"), u16::from_ne_bytes([1, 2])]
+    );
+
+    const TEST2: &[u32] = &[0x01020304, 0xa0b0c0d0];
+    const TEST3: &[u8] = cast_slice!([u32] as [u8]: TEST2);
+    assert_eq!(TEST3[..4], 0x01020304u32.to_ne_bytes());
+    assert_eq!(TEST3[4..], 0xa0b0c0d0u32.to_ne_bytes());
+
+    const TEST4: &[u64] = cast_slice!([u32] as [u64]: TEST2);
+    assert_eq!(TEST4.len(), 1);
+    assert_eq!(TEST4[0].to_ne_bytes(), TEST3);
+
+    const TEST5: &[f32] = cast_slice!([u32] as [f32]: &[0x3fc00000]);
+    assert_eq!(TEST5, [1.5]);
+
+    const TEST6: &[i16] = cast_slice!([u16] as [i16]: &[0xffff]);
+    assert_eq!(TEST6, [-1]);
+}
+
+#[test]
+fn as_bytes_smoke() {
+    use constcat::as_bytes;
+
+    #[repr(C)]
+    struct Empty {}
+
+    const TEST0: &[u8; 0] = as_bytes!(Empty {} = Empty {});
+    assert_eq!(TEST0, b"");
+
+    #[repr(C)]
+    struct Point {
+        x: u8,
+        y: u32,
+        z: [u16; 2],
+    }
+
+    const POINT: Point = Point {
+        x: 1,
+        y: 0x02030405,
+        z: [0x0607, 0x0809],
+    };
+
+    const TEST1: &[u8; 12] = as_bytes!(Point { x, y, z } = POINT, be);
+    assert_eq!(TEST1, b"\x01\0\0\0\x02\x03\x04\x05\x06\x07\x08\x09");
+
+    const TEST2: &[u8; 12] = as_bytes!(Point { z, y, x } = POINT, padding = 0xaa, le,);
+    assert_eq!(TEST2, b"\x01\xaa\xaa\xaa\x05\x04\x03\x02\x07\x06\x09\x08");
+
+    const TEST3: &[u8] = as_bytes!(Point { x, y, z } = POINT);
+    assert_eq!(&TEST3[4..8], 0x02030405u32.to_ne_bytes());
+}
+
+#[test]
+fn patch_at_smoke() {
+    use constcat::{be, crc32, hex, le, patch_at};
+
+    const TEST0: &[u8] = patch_at!([], |DATA| {});
+    assert_eq!(TEST0, b"");
+
+    const TEST1: &[u8] = patch_at!([b"\0\0", b"abc", le(7u16)], |data, count| {
+        0 => data.len() as u8,
+        1 => count as u8,
+    });
+    assert_eq!(TEST1, b"\x07\x03abc\x07\x00");
+
+    const TEST2: &[u8] = &[0; 8];
+    const TEST3: &[u8] = patch_at!([TEST2, b"payload",], |BYTES| {
+        0 => be(BYTES.len() as u32),
+        4 => le(crc32!(BYTES)),
+        0 => b"\xff",
+    });
+    assert_eq!(&TEST3[..4], b"\xff\x00\x00\x0f");
+    assert_eq!(TEST3[4..8], crc32!([0u8; 8], b"payload").to_le_bytes());
+
+    const TEST4: &[u8] =
+        patch_at!([hex!("0000"), TEST2.len() as u8, 'x'], |_data, n,| { 1 => n as u8 });
+    assert_eq!(TEST4, b"\x00\x03\x08x");
+}
+
+#[test]
+fn inet_checksum_smoke() {
+    use constcat::inet_checksum;
+
+    const TEST0: u16 = inet_checksum!();
+    assert_eq!(TEST0, 0xffff);
+
+    const TEST1: u16 = inet_checksum!(b"\x00\x01\xf2\x03\xf4\xf5\xf6\xf7");
+    assert_eq!(TEST1, !0xddf2);
+
+    const TEST2: u16 = inet_checksum!(b"\xff\xff", b'\x01');
+    assert_eq!(TEST2, !0x0100);
+}
+
+#[test]
+fn udp_packet_smoke() {
+    use constcat::{hex, inet_checksum, le, udp_packet};
+
+    const TEST0: &[u8] = udp_packet! {
+        ipv4 { src: [10, 0, 0, 1], dst: [10, 0, 0, 2], ttl: 64, id: 0x1234 },
+        udp { src_port: 1000, dst_port: 2000 },
+        payload:
+    };
+    assert_eq!(
+        TEST0,
+        hex!(
+            "4500 001c 1234 0000 4011 549b 0a00 0001 0a00 0002",
+            "03e8 07d0 0008 e023"
+        )
+    );
+    assert_eq!(inet_checksum!(TEST0.split_at(20).0), 0);
+
+    const MAC: [u8; 6] = [1, 2, 3, 4, 5, 6];
+    const TEST1: &[u8] = udp_packet! {
+        eth { dst: [0xff; 6], src: MAC, },
+        ipv4 { src: [192, 168, 1, 1], dst: [192, 168, 1, 2], ttl: 1, id: 0, },
+        udp { src_port: 53, dst_port: 53, },
+        payload: b"abc", 'd', le(1u8),
+    };
+    assert_eq!(&TEST1[..14], hex!("ffffffffffff 010203040506 0800"));
+    assert_eq!(&TEST1[14..18], hex!("4500 0021"));
+    assert_eq!(inet_checksum!(TEST1.split_at(34).0.split_at(14).1), 0);
+    assert_eq!(&TEST1[38..40], hex!("000d"));
+    assert_eq!(&TEST1[42..], b"abcd\x01");
+}
+
+#[test]
+fn firmware_header_smoke() {
+    use constcat::{crc16, firmware_header, Crc16};
+
+    const PAYLOAD: &[u8] = b"123456789";
+
+    const TEST0: &[u8; 16] = firmware_header! {
+        magic: b"IMG",
+        version: 1u8,
+        payload: PAYLOAD,
+        checksum: crc32,
+        size: 16,
+    };
+    assert_eq!(
+        TEST0,
+        b"IMG\x01\x09\x00\x00\x00\x26\x39\xf4\xcb\x00\x00\x00\x00"
+    );
+
+    const TEST1: &[u8; 12] = firmware_header! {
+        byte_order: be,
+        magic: 0x7fu8,
+        version: 0x0203u16,
+        payload: b"",
+        checksum: crc16(Crc16::XMODEM),
+        size: 12
+    };
+    assert_eq!(TEST1, b"\x7f\x02\x03\x00\x00\x00\x00\x00\x00\x00\x00\x00");
+
+    const TEST2: &[u8; 9] = firmware_header! {
+        magic: "",
+        version: 0u8,
+        payload: PAYLOAD,
+        checksum: crc16(Crc16::MODBUS),
+        size: 9,
+    };
+    assert_eq!(TEST2[5..7], crc16!(Crc16::MODBUS: PAYLOAD).to_le_bytes());
+    assert_eq!(TEST2[7..], [0, 0]);
+}
+
+#[test]
+fn elf_note_smoke() {
+    use constcat::{concat_bytes, elf_note, hex};
+
+    const fn ne(x: u32) -> [u8; 4] {
+        x.to_ne_bytes()
+    }
+
+    const TEST0: &[u8] = elf_note!("", 0);
+    assert_eq!(TEST0, concat_bytes!(ne(1), ne(0), ne(0), [0; 4]));
+
+    const TEST1: &[u8] = elf_note!("GNU", 3, hex!("0123456789"),);
+    assert_eq!(
+        TEST1,
+        concat_bytes!(ne(4), ne(5), ne(3), b"GNU\0", b"\x01\x23\x45\x67\x89\0\0\0")
+    );
+
+    const NAME: &str = "abcd";
+    const TEST2: &[u8] = elf_note!(NAME, 0x100, b"wxyz", 'q');
+    assert_eq!(
+        TEST2,
+        concat_bytes!(ne(5), ne(5), ne(0x100), b"abcd\0\0\0\0", b"wxyzq\0\0\0")
+    );
+}
+
+#[test]
+fn dns_name_smoke() {
+    use constcat::dns_name;
+
+    const TEST0: &[u8] = dns_name!();
+    assert_eq!(TEST0, b"\0");
+
+    const TEST1: &[u8] = dns_name!(".");
+    assert_eq!(TEST1, b"\0");
+
+    const TEST2: &[u8] = dns_name!("localhost");
+    assert_eq!(TEST2, b"\x09localhost\0");
+
+    const TEST3: &[u8] = dns_name!("_sip._udp.example.org.",);
+    assert_eq!(TEST3, b"\x04_sip\x04_udp\x07example\x03org\0");
+
+    const LABEL: &str = "a123456789b123456789c123456789d123456789e123456789f123456789xyz";
+    const TEST4: &[u8] = dns_name!(LABEL, ".", "io");
+    assert_eq!(TEST4.len(), 1 + 63 + 1 + 2 + 1);
+    assert_eq!(TEST4[0], 63);
+}
+
+#[test]
+fn pb_smoke() {
+    use constcat::{be, le, pb_field, pb_varint};
+
+    const TEST0: [&[u8]; 5] = [
+        pb_varint!(0),
+        pb_varint!(1u8),
+        pb_varint!(127),
+        pb_varint!(128),
+        pb_varint!(u64::MAX),
+    ];
+    assert_eq!(TEST0[..4], [&[0][..], &[1], &[0x7f], &[0x80, 0x01]]);
+    assert_eq!(TEST0[4], b"\xff\xff\xff\xff\xff\xff\xff\xff\xff\x01");
+
+    const TEST1: &[u8] = pb_varint!(-2i64);
+    assert_eq!(TEST1, b"\xfe\xff\xff\xff\xff\xff\xff\xff\xff\x01");
+
+    const TEST2: &[u8] = pb_field!(1, varint, 150);
+    assert_eq!(TEST2, b"\x08\x96\x01");
+
+    const TEST3: &[u8] = pb_field!(2, len, "testing");
+    assert_eq!(TEST3, b"\x12\x07testing");
+
+    const TEST4: &[u8] = pb_field!(16, len,);
+    assert_eq!(TEST4, b"\x82\x01\x00");
+
+    const TEST5: &[u8] = pb_field!(4, i64, le(1.0f64.to_bits()));
+    assert_eq!(TEST5, b"\x21\x00\x00\x00\x00\x00\x00\xf0\x3f");
+
+    const TEST6: &[u8] = pb_field!(5, i32, be(1u16), b"\0\0",);
+    assert_eq!(TEST6, b"\x2d\x00\x01\x00\x00");
+}
+
+#[test]
+fn cbor_smoke() {
+    use constcat::cbor;
+
+    const TEST0: &[u8] = cbor!();
+    assert_eq!(TEST0, b"");
+
+    const TEST1: &[u8] = cbor!(
+        uint(0u32),
+        uint(23u32),
+        uint(24u32),
+        uint(1000u32),
+        uint(1000000u32),
+        uint(u64::MAX)
+    );
+    assert_eq!(
+        TEST1,
+        b"\x00\x17\x18\x18\x19\x03\xe8\x1a\x00\x0f\x42\x40\x1b\xff\xff\xff\xff\xff\xff\xff\xff"
+    );
+
+    const TEST2: &[u8] = cbor!(int(-1i8), int(-100i8), int(10u8), int(-1000i16));
+    assert_eq!(TEST2, b"\x20\x38\x63\x0a\x39\x03\xe7");
+
+    const TEST3: &[u8] = cbor!(bytes(b""), bytes([1, 2, 3, 4]), text("IETF"));
+    assert_eq!(TEST3, b"\x40\x44\x01\x02\x03\x04\x64IETF");
+
+    const TEST4: &[u8] = cbor!(false, true, null, raw(b"\xf7"));
+    assert_eq!(TEST4, b"\xf4\xf5\xf6\xf7");
+
+    const TEST5: &[u8] =
+        cbor!(array [], map {}, array [uint(1u32), array [uint(2u32), uint(3u32)]]);
+    assert_eq!(TEST5, b"\x80\xa0\x82\x01\x82\x02\x03");
+
+    const TEST6: &[u8] = cbor!(map { uint(1u32) => uint(2u32), uint(3u32) => uint(4u32), });
+    assert_eq!(TEST6, b"\xa2\x01\x02\x03\x04");
+
+    const TEST7: &[u8] = cbor!(array [
+        uint(1u32), uint(2u32), uint(3u32), uint(4u32), uint(5u32), uint(6u32), uint(7u32), uint(8u32), uint(9u32),
+        uint(10u32), uint(11u32), uint(12u32), uint(13u32), uint(14u32), uint(15u32), uint(16u32), uint(17u32),
+        uint(18u32), uint(19u32), uint(20u32), uint(21u32), uint(22u32), uint(23u32), uint(24u32), uint(25u32),
+    ]);
+    assert_eq!(&TEST7[..4], b"\x98\x19\x01\x02");
+    assert_eq!(TEST7.len(), 29);
+
+    const TEST8: &[u8] = cbor!(int(i64::MIN), int(u64::MAX), uint(u128::MAX >> 64));
+    assert_eq!(
+        TEST8,
+        b"\x3b\x7f\xff\xff\xff\xff\xff\xff\xff\
+          \x1b\xff\xff\xff\xff\xff\xff\xff\xff\
+          \x1b\xff\xff\xff\xff\xff\xff\xff\xff"
+    );
+
+    const TEST9: &[u8] = cbor!(map { text("a") => map { uint(1u8) => array [null] } });
+    assert_eq!(TEST9, b"\xa1\x61a\xa1\x01\x81\xf6");
+}
+
+#[test]
+fn uuid_smoke() {
+    use constcat::{concat_bytes, uuid_bytes, uuid_str};
+
+    const TEST0: &[u8; 16] = uuid_bytes!("00000000-0000-0000-0000-000000000000");
+    assert_eq!(TEST0, &[0; 16]);
+
+    const TEST1: &str = "123e4567-E89B-12d3-a456-426614174000";
+    const TEST2: &[u8; 16] = uuid_bytes!(TEST1);
+    assert_eq!(
+        TEST2,
+        b"\x12\x3e\x45\x67\xe8\x9b\x12\xd3\xa4\x56\x42\x66\x14\x17\x40\x00"
+    );
+
+    const TEST3: [u8; 16] = *uuid_bytes!(be: TEST1);
+    assert_eq!(&TEST3, TEST2);
+
+    const TEST4: &[u8; 16] = uuid_bytes!(le: TEST1);
+    assert_eq!(
+        TEST4,
+        b"\x00\x40\x17\x14\x66\x42\x56\xa4\xd3\x12\x9b\xe8\x67\x45\x3e\x12"
+    );
+
+    const TEST5: &[u8] = concat_bytes!(b'<', uuid_bytes!(TEST1), b'>');
+    assert_eq!(TEST5.len(), 18);
+
+    const TEST6: &str = uuid_str!(TEST1);
+    assert_eq!(TEST6, "123e4567-e89b-12d3-a456-426614174000");
+
+    const TEST7: &str = uuid_str!("FFFFFFFF-FFFF-FFFF-FFFF-FFFFFFFFFFFF");
+    assert_eq!(TEST7, "ffffffff-ffff-ffff-ffff-ffffffffffff");
+}
+
+#[test]
+fn uuid_str_from_bytes_smoke() {
+    use constcat::{be, uuid_bytes, uuid_str, uuid_str_from_bytes};
+
+    const TEST0: &str = uuid_str_from_bytes!([0; 16]);
+    assert_eq!(TEST0, "00000000-0000-0000-0000-000000000000");
+
+    const TEST1: &str = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+    const TEST2: &[u8; 16] = uuid_bytes!(TEST1);
+    assert_eq!(uuid_str_from_bytes!(TEST2), TEST1);
+    assert_eq!(uuid_str_from_bytes!(lower: TEST2), uuid_str!(TEST1));
+    assert_eq!(
+        uuid_str_from_bytes!(upper: TEST2),
+        "6BA7B810-9DAD-11D1-80B4-00C04FD430C8"
+    );
+    assert_eq!(
+        uuid_str_from_bytes!(registry: TEST2),
+        "{6BA7B810-9DAD-11D1-80B4-00C04FD430C8}"
+    );
+
+    const TEST3: &str =
+        uuid_str_from_bytes!(b"\x01\x23\x45\x67", be(0x89abu16), [0xcd, 0xef], &[0xff; 8]);
+    assert_eq!(TEST3, "01234567-89ab-cdef-ffff-ffffffffffff");
+
+    const TEST4: &str = uuid_str_from_bytes!(guid: b"\x67\x45\x23\x01\xab\x89\xef\xcd", [0xff; 8]);
+    assert_eq!(TEST4, "01234567-89ab-cdef-ffff-ffffffffffff");
+    assert_eq!(
+        uuid_str_from_bytes!(be: guid: upper: TEST2),
+        "10B8A76B-AD9D-D111-80B4-00C04FD430C8"
+    );
+}
+
+#[test]
+fn tar_smoke() {
+    use constcat::tar;
+
+    const TEST0: &[u8] = tar!();
+    assert_eq!(TEST0, &[0; 1024][..]);
+
+    const TEST1: &[u8] = tar!(("a.txt", b"abc"));
+    assert_eq!(TEST1.len(), 2048);
+    assert_eq!(&TEST1[..6], b"a.txt\0");
+    assert_eq!(&TEST1[100..108], b"0000644\0");
+    assert_eq!(&TEST1[124..136], b"00000000003\0");
+    assert_eq!(&TEST1[148..156], b"006717\0 ");
+    assert_eq!(TEST1[156], b'0');
+    assert_eq!(&TEST1[257..265], b"ustar\x0000");
+    assert_eq!(&TEST1[512..516], b"abc\0");
+    assert!(TEST1[516..].iter().all(|&b| b == 0));
+
+    const TEST2: &str = "dir/";
+    const TEST3: &[u8] = tar!((TEST2, b"", 0o700), ("dir/x", [1u8; 512],),);
+    assert_eq!(TEST3.len(), 3 * 512 + 1024);
+    assert_eq!(&TEST3[100..108], b"0000700\0");
+    assert_eq!(TEST3[156], b'5');
+    assert_eq!(&TEST3[512 + 124..512 + 136], b"00000001000\0");
+
+    const TEST4: &str = concat!(
+        "a123456789b123456789c123456789d123456789e123456789",
+        "/",
+        "f123456789g123456789h123456789i123456789j123456789k123456789",
+    );
+    const TEST5: &[u8] = tar!((TEST4, b""));
+    assert_eq!(&TEST5[..61], [&TEST4.as_bytes()[51..], b"\0"].concat());
+    assert_eq!(&TEST5[345..395], &TEST4.as_bytes()[..50]);
+}
+
+#[test]
+fn cpio_smoke() {
+    use constcat::cpio;
+
+    const TEST0: &[u8] = cpio!();
+    assert_eq!(
+        TEST0,
+        concat!(
+            "070701",
+            "00000000000000000000000000000000000000010000000000000000",
+            "00000000000000000000000000000000",
+            "0000000B00000000",
+            "TRAILER!!!\0\0\0\0"
+        )
+        .as_bytes()
+    );
+
+    const TEST1: &str = "hi\n";
+    const TEST2: &[u8] = cpio!(("dev/", b"", 0o700), ("dev/x", TEST1,),);
+    assert_eq!(&TEST2[6..46], b"00000001000041C0000000000000000000000002");
+    assert_eq!(&TEST2[110..116], b"dev\0\0\0");
+    assert_eq!(&TEST2[116 + 6..116 + 22], b"00000002000081A4");
+    assert_eq!(&TEST2[116 + 54..116 + 62], b"00000003");
+    assert_eq!(&TEST2[116 + 110..116 + 120], b"dev/x\0hi\n\0");
+    assert_eq!(TEST2.len(), 116 + 120 + 124);
+}
+
+#[test]
+fn bitmap_smoke() {
+    use constcat::bitmap;
+
+    const TEST0: &[u8] = bitmap!(width = 3: "");
+    assert_eq!(TEST0, b"");
+
+    const TEST1: &[u8] = bitmap!(width = 8: "#......#\n.#....#.", "\r\n\t########\n");
+    assert_eq!(TEST1, [0x81, 0x42, 0xff]);
+
+    const TEST2: &str = "
+        ###.....#
+        .........
+    ";
+    const TEST3: &[u8] = bitmap!(width = 9: TEST2);
+    assert_eq!(TEST3, [0xe0, 0x80, 0x00, 0x00]);
+
+    const TEST4: &[u8] = bitmap!(width = 2, on = '1', off = '0': "10", "\n01");
+    assert_eq!(TEST4, [0x80, 0x40]);
+
+    const TEST5: &[u8] = bitmap!(width = 16: "################\n");
+    assert_eq!(TEST5, [0xff, 0xff]);
+}
+
+#[test]
+fn ascii_case_smoke() {
+    use constcat::{ascii_lower, ascii_upper, concat};
+
+    const TEST0: &str = ascii_upper!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = "Hello, Wörld! ß";
+    const TEST2: &str = ascii_upper!(TEST1);
+    assert_eq!(TEST2, "HELLO, WöRLD! ß");
+
+    const TEST3: &str = ascii_lower!(TEST1, " ", 42, '!', true);
+    assert_eq!(TEST3, "hello, wörld! ß 42!true");
+
+    const TEST4: &str = concat!(ascii_lower!("ABC"), ascii_upper!("def"));
+    assert_eq!(TEST4, "abcDEF");
+}
+
+#[test]
+fn to_case_smoke() {
+    use constcat::to_case;
+
+    const TEST0: &str = to_case!(snake: "");
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = "  getHTTPResponse_code2XX--v2 ";
+    assert_eq!(to_case!(snake: TEST1), "get_http_response_code2_xx_v2");
+    assert_eq!(to_case!(screaming: TEST1), "GET_HTTP_RESPONSE_CODE2_XX_V2");
+    assert_eq!(to_case!(kebab: TEST1), "get-http-response-code2-xx-v2");
+    assert_eq!(to_case!(camel: TEST1), "getHttpResponseCode2XxV2");
+    assert_eq!(to_case!(pascal: TEST1), "GetHttpResponseCode2XxV2");
+
+    const TEST2: &str = to_case!(camel: "MAX_RETRY", "_COUNT");
+    assert_eq!(TEST2, "maxRetryCount");
+
+    const TEST3: &str = to_case!(snake: "crème brûlée", ".Écrit");
+    assert_eq!(TEST3, "crème_brûlée_Écrit");
+
+    const TEST4: &str = to_case!(pascal: "a", "b c", 1, "D");
+    assert_eq!(TEST4, "AbC1D");
+}
+
+#[test]
+fn title_case_smoke() {
+    use constcat::{title_case, to_case};
+
+    const TEST0: &str = title_case!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = title_case!("  hello\twORLD\n");
+    assert_eq!(TEST1, "  Hello\tWorld\n");
+
+    const TEST2: &str = title_case!("the 2nd ", "o'neil ", "élan vital");
+    assert_eq!(TEST2, "The 2nd O'neil élan Vital");
+
+    const TEST3: &str = title_case!(to_case!(kebab: "logLevel"));
+    assert_eq!(TEST3, "Log-Level");
+}
+
+#[test]
+fn pad_str_smoke() {
+    use constcat::{center, pad_left, pad_right};
+
+    const TEST0: &str = pad_left!(width = 0:);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = pad_left!(width = 3:);
+    assert_eq!(TEST1, "   ");
+
+    const TEST2: &str = "héllo";
+    assert_eq!(pad_left!(width = 7: TEST2), "  héllo");
+    assert_eq!(pad_right!(width = 7: TEST2), "héllo  ");
+    assert_eq!(pad_left!(width = 5: TEST2), "héllo");
+    assert_eq!(pad_right!(width = 2: TEST2), "héllo");
+
+    const TEST3: usize = 2;
+    const TEST4: &str = pad_right!(width = TEST3 * 3, fill = '·': "a", 1, true);
+    assert_eq!(TEST4, "a1true");
+
+    const TEST5: &str = pad_left!(width = 4, fill = '😀': 'x');
+    assert_eq!(TEST5, "😀😀😀x");
+
+    assert_eq!(center!(width = 7: TEST2), " héllo ");
+    assert_eq!(center!(width = 8, fill = '*': TEST2), "*héllo**");
+    assert_eq!(center!(width = 3: TEST2), "héllo");
+
+    const TEST6: &str = center!(width = 3, fill = '=':);
+    assert_eq!(TEST6, "===");
+}
+
+#[test]
+fn trim_smoke() {
+    use constcat::{trim, trim_matches};
+
+    const TEST0: &str = trim!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = trim!(" \t\n\r ");
+    assert_eq!(TEST1, "");
+
+    const TEST2: &str = "\u{3000} ünïcödé \u{2003}\n";
+    assert_eq!(trim!(TEST2), "ünïcödé");
+    assert_eq!(trim!(start: TEST2), "ünïcödé \u{2003}\n");
+    assert_eq!(trim!(end: TEST2), "\u{3000} ünïcödé");
+
+    const TEST3: &str = trim!(" a ", 1, " b ");
+    assert_eq!(TEST3, "a 1 b");
+
+    const TEST4: &str = "ééxéé";
+    assert_eq!(trim_matches!(['é']: TEST4), "x");
+    assert_eq!(trim_matches!(start ['é', 'x']: TEST4), "");
+    assert_eq!(trim_matches!(end ['é',]: TEST4), "ééx");
+    assert_eq!(trim_matches!([]: TEST4), TEST4);
+
+    const TEST5: char = '/';
+    const TEST6: &str = trim_matches!([TEST5, ' ']: "/ /path/to/ ");
+    assert_eq!(TEST6, "path/to");
+}
+
+#[test]
+fn replace_smoke() {
+    use constcat::{concat, replace};
+
+    const TEST0: &str = replace!("", "a", "b");
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = replace!("aaaa", "aa", "b");
+    assert_eq!(TEST1, "bb");
+
+    const TEST2: &str = replace!("aaa", "aa", "");
+    assert_eq!(TEST2, "a");
+
+    const TEST3: &str = replace!("héllo", "", "-");
+    assert_eq!(TEST3, "héllo".replace("", "-"));
+
+    const TEST4: &str = replace!("", "", "x");
+    assert_eq!(TEST4, "x");
+
+    const TEST5: &str = "C:\\Program Files\\app";
+    const TEST6: &str = replace!(TEST5, '\\', "/");
+    assert_eq!(TEST6, "C:/Program Files/app");
+
+    const TEST7: &str = replace!(concat!("v", 1, ".", 2), ".", "_");
+    assert_eq!(TEST7, "v1_2");
+
+    const TEST8: &str = replace!("ünïcödé", "ï", "i");
+    assert_eq!(TEST8, "ünicödé");
+}
+
+#[test]
+fn strip_smoke() {
+    use constcat::{strip_prefix, strip_suffix};
+
+    const TEST0: &str = strip_prefix!("", "");
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = strip_suffix!("abc", "");
+    assert_eq!(TEST1, "abc");
+
+    const TEST2: &str = "ünïcödé";
+    assert_eq!(strip_prefix!(TEST2, "ün"), "ïcödé");
+    assert_eq!(strip_suffix!(TEST2, "dé"), "ünïcö");
+    assert_eq!(strip_prefix!(TEST2, TEST2), "");
+    assert_eq!(strip_prefix!(optional: TEST2, "dé"), TEST2);
+    assert_eq!(strip_suffix!(optional: TEST2, "ün"), TEST2);
+    assert_eq!(strip_suffix!(optional: "a", "aa"), "a");
+
+    const TEST3: &str = strip_suffix!("aa", 'a');
+    assert_eq!(TEST3, "a");
+}
+
+#[test]
+fn reverse_str_smoke() {
+    use constcat::reverse_str;
+
+    const TEST0: &str = reverse_str!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = reverse_str!("a");
+    assert_eq!(TEST1, "a");
+
+    const TEST2: &str = "ä€😀b";
+    const TEST3: &str = reverse_str!(TEST2);
+    assert_eq!(TEST3, "b😀€ä");
+    assert_eq!(reverse_str!(TEST3), TEST2);
+
+    const TEST4: &str = reverse_str!("שלום", ' ', "עולם");
+    assert_eq!(TEST4, "שלום עולם".chars().rev().collect::<String>());
+}
+
+#[test]
+fn truncate_chars_smoke() {
+    use constcat::truncate_chars;
+
+    const TEST0: &str = truncate_chars!("", 0);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = truncate_chars!("abc", 0);
+    assert_eq!(TEST1, "");
+
+    const TEST2: &str = "😀é€a";
+    assert_eq!(truncate_chars!(TEST2, 1), "😀");
+    assert_eq!(truncate_chars!(TEST2, 3), "😀é€");
+    assert_eq!(truncate_chars!(TEST2, 4), TEST2);
+    assert_eq!(truncate_chars!(TEST2, 100), TEST2);
+    assert_eq!(truncate_chars!(TEST2, 3, "..."), "...");
+    assert_eq!(truncate_chars!(TEST2, 3, '~'), "😀é~");
+    assert_eq!(truncate_chars!(TEST2, 4, "..."), TEST2);
+
+    const TEST3: usize = 2;
+    const TEST4: &str = truncate_chars!("hello", TEST3 + 1, "…",);
+    assert_eq!(TEST4, "he…");
+}
+
+#[test]
+fn indent_smoke() {
+    use constcat::{concat, indent};
+
+    const TEST0: &str = indent!("  ":);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = indent!("> ": "a", "\n", "b");
+    assert_eq!(TEST1, "> a\n> b");
+
+    const TEST2: &str = indent!("\t": "\n\r\na\r\n  \n");
+    assert_eq!(TEST2, "\n\r\n\ta\r\n\t  \n");
+
+    const TEST3: &str = "- ";
+    const TEST4: &str = indent!(TEST3: "x\ny");
+    assert_eq!(TEST4, "- x\n- y");
+
+    const TEST5: &str = indent!(concat!("/", "/ "): "é");
+    assert_eq!(TEST5, "// é");
+}
+
+#[test]
+fn dedent_smoke() {
+    use constcat::dedent;
+
+    const TEST0: &str = dedent!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = dedent!("  \n\t\n");
+    assert_eq!(TEST1, "\n\n");
+
+    const TEST2: &str = dedent!("  a\n    b\n  c");
+    assert_eq!(TEST2, "a\n  b\nc");
+
+    const TEST3: &str = dedent!("\tx\n  y\n");
+    assert_eq!(TEST3, "\tx\n  y\n");
+
+    const TEST4: &str = dedent!("    ü\n", "      \n", "  ö  \n", "    ");
+    assert_eq!(TEST4, "  ü\n\nö  \n");
+
+    const TEST5: &str = "  \ta\n  \t  b\n  c";
+    assert_eq!(dedent!(TEST5), "\ta\n\t  b\nc");
+}
+
+#[test]
+fn wrap_smoke() {
+    use constcat::wrap;
+
+    const TEST0: &str = wrap!("", 10);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = wrap!("aa bb cc", 5);
+    assert_eq!(TEST1, "aa bb\ncc");
+
+    const TEST2: &str = wrap!("aa  bb\t\tcc  \n\n  ddd eee\n", 6);
+    assert_eq!(TEST2, "aa bb\ncc\n\n  ddd\n  eee\n");
+
+    const TEST3: &str = wrap!("supercalifragilistic is long", 5);
+    assert_eq!(TEST3, "supercalifragilistic\nis\nlong");
+
+    const TEST4: &str = "ééé ààà ööö";
+    assert_eq!(wrap!(TEST4, 7), "ééé ààà\nööö");
+    assert_eq!(wrap!(TEST4, 0), "ééé\nààà\nööö");
+    assert_eq!(wrap!(TEST4, 100), TEST4);
+}
+
+#[test]
+fn collapse_whitespace_smoke() {
+    use constcat::collapse_whitespace;
+
+    const TEST0: &str = collapse_whitespace!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = collapse_whitespace!(" \t\r\n ");
+    assert_eq!(TEST1, "");
+
+    const TEST2: &str = "{\n  \"a\": [1,   2],\r\n\t\"b\" : \"x  y\"\n}\n";
+    assert_eq!(
+        collapse_whitespace!(TEST2),
+        "{\n\"a\": [1, 2],\n\"b\" : \"x y\"\n}"
+    );
+    assert_eq!(
+        collapse_whitespace!(single_line: TEST2),
+        "{ \"a\": [1, 2], \"b\" : \"x y\" }"
+    );
+
+    const TEST3: &str = collapse_whitespace!("é ", " ", "\u{a0}ü");
+    assert_eq!(TEST3, "é \u{a0}ü");
+}
+
+#[test]
+fn split_smoke() {
+    use constcat::{concat, split};
+
+    const TEST0: &[&str] = split!("", ",");
+    assert_eq!(TEST0, [""]);
+
+    const TEST1: &[&str] = split!(",", ',');
+    assert_eq!(TEST1, ["", ""]);
+
+    const TEST2: &str = "a::b:c::";
+    const TEST3: &[&str] = split!(TEST2, "::");
+    assert_eq!(TEST3, ["a", "b:c", ""]);
+
+    const TEST4: [&str; 3] = *split!("ü→é→ö", '→');
+    assert_eq!(TEST4, ["ü", "é", "ö"]);
+
+    const TEST5: &[&str] = split!(concat!("x", 1, "x", 2), "x");
+    assert_eq!(TEST5, ["", "1", "2"]);
+
+    const TEST6: &[&str] = split!("aaa", "aa");
+    assert_eq!(TEST6, ["", "a"]);
+}
+
+#[test]
+fn split_once_smoke() {
+    use constcat::split_once;
+
+    const TEST0: (&str, &str) = split_once!("=", '=');
+    assert_eq!(TEST0, ("", ""));
+
+    const TEST1: &str = "key = a = b";
+    assert_eq!(split_once!(TEST1, " = "), ("key", "a = b"));
+    assert_eq!(split_once!(TEST1, ""), ("", TEST1));
+
+    const TEST2: (&str, &str) = split_once!("ü→é→ö", "→");
+    assert_eq!(TEST2, ("ü", "é→ö"));
+}
+
+#[test]
+fn lines_smoke() {
+    use constcat::lines;
+
+    const TEST0: &[&str] = lines!();
+    assert_eq!(TEST0, [""; 0]);
+
+    const TEST1: &[&str] = lines!("\n");
+    assert_eq!(TEST1, [""]);
+
+    const TEST2: &str = "a\r\nb\n\r\n\nc\r";
+    assert_eq!(lines!(TEST2)[..], TEST2.lines().collect::<Vec<_>>());
+    assert_eq!(lines!(skip_empty: TEST2)[..], ["a", "b", "c\r"]);
+
+    const TEST3: &[&str] = lines!(skip_empty: "\n\n", "\r\n");
+    assert_eq!(TEST3, [""; 0]);
+
+    const TEST4: [&str; 2] = *lines!("ü", "\n", "é");
+    assert_eq!(TEST4, ["ü", "é"]);
+}
+
+#[test]
+fn utf8_smoke() {
+    use constcat::utf8;
+
+    const TEST0: &str = utf8!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &[u8] = "ü€😀".as_bytes();
+    const TEST2: &str = utf8!(TEST1, b'!', [0xc3, 0xa9]);
+    assert_eq!(TEST2, "ü€😀!é");
+}
+
+#[test]
+fn c_escape_smoke() {
+    use constcat::c_escape;
+
+    const TEST0: &str = c_escape!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = c_escape!("\0\x01\x07\x1f\x7f a");
+    assert_eq!(TEST1, r"\000\001\007\037\177 a");
+
+    const TEST2: &str = "C:\\dir\t\"ü\"\r\n";
+    assert_eq!(c_escape!(TEST2), r#"C:\\dir\t\"ü\"\r\n"#);
+
+    const TEST3: &str = c_escape!('\'', "?", 1);
+    assert_eq!(TEST3, "'?1");
+}
+
+#[test]
+fn json_escape_smoke() {
+    use constcat::json_escape;
+
+    const TEST0: &str = json_escape!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = json_escape!("\0\x08\x0c\x1f\x7f/");
+    assert_eq!(TEST1, "\\u0000\\b\\f\\u001f\x7f/");
+
+    const TEST2: &str = "\"a\\b\"\r\n\tü€😀";
+    assert_eq!(json_escape!(TEST2), r#"\"a\\b\"\r\n\tü€😀"#);
+    assert_eq!(
+        json_escape!(ascii: TEST2),
+        r#"\"a\\b\"\r\n\t\u00fc\u20ac\ud83d\ude00"#
+    );
+
+    const TEST3: &str = json_escape!(ascii: '\u{10ffff}', 1);
+    assert_eq!(TEST3, "\\udbff\\udfff1");
+}
+
+#[test]
+fn html_escape_smoke() {
+    use constcat::html_escape;
+
+    const TEST0: &str = html_escape!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = r#"<a href="x?a=1&b='2'">ü</a>"#;
+    const TEST2: &str = html_escape!(TEST1);
+    assert_eq!(
+        TEST2,
+        "&lt;a href=&quot;x?a=1&amp;b=&#39;2&#39;&quot;&gt;ü&lt;/a&gt;"
+    );
+
+    const TEST3: &str = html_escape!('&', 1, "\n");
+    assert_eq!(TEST3, "&amp;1\n");
+}
+
+#[test]
+fn urlencode_smoke() {
+    use constcat::urlencode;
+
+    const TEST0: &str = urlencode!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = "aZ09-._~ /?#[]@!$&'()*+,;=%\n€";
+    assert_eq!(
+        urlencode!(TEST1),
+        "aZ09-._~%20%2F%3F%23%5B%5D%40%21%24%26%27%28%29%2A%2B%2C%3B%3D%25%0A%E2%82%AC"
+    );
+    assert_eq!(urlencode!(component: TEST1), urlencode!(TEST1));
+    assert_eq!(
+        urlencode!(path: TEST1),
+        "aZ09-._~%20/%3F%23%5B%5D@!$&'()*+,;=%25%0A%E2%82%AC"
+    );
+    assert_eq!(
+        urlencode!(query: TEST1),
+        "aZ09-._~+%2F%3F%23%5B%5D%40%21%24%26%27%28%29%2A%2B%2C%3B%3D%25%0A%E2%82%AC"
+    );
+
+    const TEST2: &str = urlencode!("a b", 1, '/');
+    assert_eq!(TEST2, "a%20b1%2F");
+}
+
+#[test]
+fn obfuscate_smoke() {
+    use constcat::{obfuscate, Obfuscated};
+
+    const TEST0: Obfuscated<0> = obfuscate!();
+    assert_eq!(TEST0.decode(), []);
+
+    const TEST1: &str = "hunter2";
+    const TEST2: Obfuscated<7> = obfuscate!(TEST1);
+    assert_eq!(TEST2.key().len(), 4);
+    assert_ne!(TEST2.as_bytes(), TEST1.as_bytes());
+    assert_eq!(&TEST2.decode(), TEST1.as_bytes());
+
+    let test3 = obfuscate!(TEST1);
+    assert_ne!(test3.key(), TEST2.key());
+    assert_eq!(test3.decode(), TEST2.decode());
+
+    const TEST4: Obfuscated<4> = obfuscate!(key = [0xff, 0x00]: "ab", 12);
+    assert_eq!(TEST4.as_bytes(), &[!b'a', b'b', !b'1', b'2']);
+    assert_eq!(&TEST4.decode(), b"ab12");
+
+    #[allow(non_upper_case_globals)]
+    const key: &str = "key";
+    const TEST5: Obfuscated<6> = obfuscate!(key, key);
+    assert_eq!(&TEST5.decode(), b"keykey");
+
+    mod keys {
+        pub const ONES: &[u8] = &[0xff];
+    }
+    const TEST6: Obfuscated<2> = obfuscate!(key = keys::ONES: "ab");
+    const TEST7: Obfuscated<2> = obfuscate!(key = { keys::ONES }: "ab");
+    assert_eq!(TEST6.as_bytes(), &[!b'a', !b'b']);
+    assert_eq!(TEST7.as_bytes(), TEST6.as_bytes());
+}
+
+#[test]
+fn slugify_smoke() {
+    use constcat::{slugify, to_ident};
+
+    const TEST0: &str = slugify!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = "--My Cool_Crate!! 2024--";
+    const TEST2: &str = slugify!(TEST1);
+    assert_eq!(TEST2, "my-cool-crate-2024");
+
+    const TEST3: &str = slugify!(sep = '.': TEST1, "ü", 'x');
+    assert_eq!(TEST3, "my.cool.crate.2024.x");
+
+    const TEST4: &str = to_ident!();
+    assert_eq!(TEST4, "_");
+
+    const TEST5: &str = to_ident!(TEST1);
+    assert_eq!(TEST5, "_My_Cool_Crate_2024_");
+
+    const TEST6: &str = to_ident!("0", "x", '_', "ü");
+    assert_eq!(TEST6, "_0x__");
+
+    #[allow(non_upper_case_globals)]
+    const sep: char = '_';
+    const TEST7: &str = slugify!(sep, "Sep");
+    assert_eq!(TEST7, "sep");
+
+    mod seps {
+        pub const DOT: char = '.';
+    }
+    const TEST8: &str = slugify!(sep = seps::DOT: "a b");
+    const TEST9: &str = slugify!(sep = { seps::DOT }: "a b");
+    assert_eq!((TEST8, TEST9), ("a.b", "a.b"));
+}
+
+#[test]
+fn assert_contains_smoke() {
+    use constcat::{assert_contains, assert_ends_with, assert_starts_with, concat};
+
+    const TEST0: &str = concat!("SELECT ", "id", " FROM users");
+    assert_contains!(TEST0, "id");
+    assert_contains!(TEST0, b"FROM");
+    assert_contains!(TEST0, "");
+    assert_starts_with!(TEST0, "SELECT");
+    assert_starts_with!(TEST0, 'S');
+    assert_ends_with!(TEST0, "users",);
+
+    const TEST1: &[u8] = b"\r\n\r\n";
+    assert_contains!(TEST1, TEST1);
+    assert_starts_with!(TEST1, [b'\r', b'\n']);
+    assert_ends_with!(TEST1, b"\n");
+    assert_ends_with!("", "");
+}
+
+#[test]
+fn const_eq_smoke() {
+    use constcat::{concat, concat_bytes, const_bytes_eq, const_str_eq};
+
+    const _: () = assert!(const_str_eq!("", ""));
+
+    const TEST1: &str = concat!("a", "bc");
+    const _: () = assert!(const_str_eq!(TEST1, concat!("ab", 'c')));
+    assert!(!const_str_eq!(TEST1, "ab"));
+    assert!(!const_str_eq!(TEST1, "abd"));
+
+    const TEST3: &[u8] = concat_bytes!(b"a", [b'b']);
+    const _: () = assert!(const_bytes_eq!(TEST3, b"ab"));
+    assert!(!const_bytes_eq!(TEST3, b"abc"));
+    assert!(!const_bytes_eq!(b"", TEST3));
+
+    const fn is_magic(b: &[u8]) -> bool {
+        const_bytes_eq!(b, b"\x7fELF")
+    }
+    assert!(is_magic(b"\x7fELF"));
+}
+
+#[test]
+fn assert_ascii_smoke() {
+    use constcat::{assert_ascii, assert_printable, concat};
+
+    assert_ascii!();
+    assert_printable!();
+
+    const TEST0: &str = concat!("AT+CMGS=", "\"123\"", "\r");
+    assert_ascii!(TEST0);
+    assert_ascii!(TEST0, [0x00, 0x7f], b"\x1b[0m");
+
+    const TEST1: &str = concat!(" !", "azAZ09", '~');
+    assert_printable!(TEST1);
+    assert_printable!(TEST1, b"[]", "{}");
+}
+
+#[test]
+fn char_count_smoke() {
+    use constcat::char_count;
+
+    const TEST0: usize = char_count!();
+    assert_eq!(TEST0, 0);
+
+    const TEST1: &str = "añ€😀";
+    const TEST2: usize = char_count!(TEST1, 'b', 1);
+    assert_eq!(TEST2, 6);
+}
+
+#[cfg(feature = "width")]
+#[test]
+fn display_width_smoke() {
+    use constcat::display_width;
+
+    const TEST0: usize = display_width!();
+    assert_eq!(TEST0, 0);
+
+    const TEST1: usize = display_width!("abc", '\t', "\u{7f}", "\u{200b}");
+    assert_eq!(TEST1, 3);
+
+    const TEST2: usize = display_width!("한국어", "ｆｕｌｌ", "😀", "\u{ff61}");
+    assert_eq!(TEST2, 17);
+
+    const TEST3: usize = display_width!("a\u{20dd}", "\u{1100}\u{1161}\u{11a8}", "\u{ad}");
+    assert_eq!(TEST3, 4);
+}
+
+#[test]
+fn parse_smoke() {
+    use constcat::{
+        parse_bool, parse_i128, parse_i16, parse_i32, parse_i64, parse_i8, parse_isize, parse_u128,
+        parse_u16, parse_u32, parse_u64, parse_u8, parse_usize,
+    };
+
+    const TEST0: u32 = parse_u32!("4294967295");
+    assert_eq!(TEST0, u32::MAX);
+
+    const TEST1: &str = "12";
+    const TEST2: u32 = parse_u32!("+", TEST1, 3);
+    assert_eq!(TEST2, 123);
+
+    const TEST3: u8 = parse_u8!(radix = 2: "1111", "0000");
+    assert_eq!(TEST3, 0xf0);
+
+    const TEST4: u16 = parse_u16!(radix = 36: "Zz");
+    assert_eq!(TEST4, 35 * 36 + 35);
+
+    const TEST5: u64 = parse_u64!(radix = 16: "FFFFFFFFFFFFFFFF");
+    assert_eq!(TEST5, u64::MAX);
+
+    const TEST6: usize = parse_usize!("0");
+    assert_eq!(TEST6, 0);
+
+    const TEST7: i32 = parse_i32!("-2147483648");
+    assert_eq!(TEST7, i32::MIN);
+
+    const TEST8: i64 = parse_i64!(radix = 8: "-", "777",);
+    assert_eq!(TEST8, -0o777);
+
+    const TEST9: bool = parse_bool!("tr", "ue");
+    const TEST10: bool = parse_bool!("false");
+    assert_eq!((TEST9, TEST10), (true, false));
+
+    const TEST11: i8 = parse_i8!("-128");
+    const TEST12: i16 = parse_i16!(radix = 16: "7fff");
+    const TEST13: isize = parse_isize!("-", "1");
+    assert_eq!((TEST11, TEST12, TEST13), (i8::MIN, i16::MAX, -1));
+
+    const TEST14: u128 = parse_u128!("340282366920938463463374607431768211455");
+    const TEST15: i128 = parse_i128!("-170141183460469231731687303715884105728");
+    assert_eq!((TEST14, TEST15), (u128::MAX, i128::MIN));
+
+    mod radix {
+        pub const HEX: u32 = 16;
+    }
+    const RADIX: u32 = 2;
+    const TEST16: u32 = parse_u32!(radix = RADIX: "101");
+    const TEST17: u32 = parse_u32!(radix = radix::HEX: "ff");
+    const TEST18: u32 = parse_u32!(radix = { RADIX * 4 }: "17");
+    assert_eq!((TEST16, TEST17, TEST18), (5, 0xff, 0o17));
+}
+
+#[test]
+fn fmt_radix_smoke() {
+    use constcat::{concat, fmt_bin, fmt_hex, fmt_oct};
+
+    const TEST0: u32 = 0xdeadbeef;
+    const TEST1: &str = fmt_hex!(TEST0);
+    assert_eq!(TEST1, "deadbeef");
+
+    const TEST2: &str = concat!("addr = ", fmt_hex!(TEST0, upper, prefix,));
+    assert_eq!(TEST2, "addr = 0xDEADBEEF");
+
+    const TEST3: &str = fmt_hex!(0u64, width = 4 * 4);
+    assert_eq!(TEST3, "0000000000000000");
+
+    const TEST4: &str = fmt_hex!(u128::MAX, prefix);
+    assert_eq!(TEST4, "0xffffffffffffffffffffffffffffffff");
+
+    const TEST5: &str = fmt_hex!(i32::MIN, width = 2);
+    assert_eq!(TEST5, "80000000");
+
+    const TEST6: &str = fmt_bin!(5u8, width = 1, prefix);
+    assert_eq!(TEST6, "0b101");
+
+    const TEST7: &str = fmt_bin!(-1i8);
+    assert_eq!(TEST7, "11111111");
+
+    const TEST8: &str = fmt_oct!(u128::MAX);
+    assert_eq!(TEST8, "3777777777777777777777777777777777777777777");
+
+    const TEST9: &str = fmt_oct!(8usize, prefix);
+    assert_eq!(TEST9, "0o10");
+}
+
+#[test]
+fn fmt_float_smoke() {
+    use constcat::{concat, fmt_float};
+
+    const TEST0: f64 = 0.1;
+    const TEST1: &str = concat!("x = ", fmt_float!(TEST0), ";");
+    assert_eq!(TEST1, "x = 0.1;");
+
+    const TEST2: &str = fmt_float!(0.1f32 as f64);
+    assert_eq!(TEST2, "0.10000000149011612");
+
+    const TEST3: &str = fmt_float!(1e21f64);
+    assert_eq!(TEST3, "1000000000000000000000");
+
+    const TEST4: &str = fmt_float!(5e-324f64);
+    assert_eq!(TEST4, format!("{}", 5e-324f64));
+
+    const TEST5: &str = fmt_float!(f64::MAX);
+    assert_eq!(TEST5, format!("{}", f64::MAX));
+
+    const TEST6: &str = fmt_float!(-0.0f32, precision = 2);
+    assert_eq!(TEST6, "-0.00");
+
+    const TEST7: &str = fmt_float!(0.125f64, precision = 2,);
+    assert_eq!(TEST7, "0.12");
+
+    const TEST8: &str = fmt_float!(9.999f32, precision = 2);
+    assert_eq!(TEST8, "10.00");
+
+    const TEST9: &str = fmt_float!(f64::NEG_INFINITY, precision = 3);
+    assert_eq!(TEST9, "-inf");
+}
+
+#[test]
+fn fmt_int_smoke() {
+    use constcat::{concat, fmt_float, fmt_int};
+
+    const TEST0: u8 = 0;
+    const TEST1: &str = fmt_int!(TEST0);
+    assert_eq!(TEST1, "0");
+
+    const TEST2: &str = concat!("[", fmt_int!(i128::MIN, sep = ','), "]");
+    assert_eq!(
+        TEST2,
+        "[-170,141,183,460,469,231,731,687,303,715,884,105,728]"
+    );
+
+    const TEST3: &str = fmt_int!(123usize, sep = ',', width = 6);
+    assert_eq!(TEST3, "   123");
+
+    const TEST4: &str = fmt_int!(1234u16, sep = '\u{2009}');
+    assert_eq!(TEST4, "1\u{2009}234");
+
+    const TEST5: &str = fmt_int!(12i64, zero, sign, width = 5,);
+    assert_eq!(TEST5, "+0012");
+
+    const TEST6: &str = fmt_int!(-12345i32, width = 3);
+    assert_eq!(TEST6, "-12345");
+
+    const TEST7: &str = fmt_int!(1000u32, sep = '.', width = 8, zero);
+    assert_eq!(TEST7, "0001.000");
+
+    const TEST8: &str = fmt_float!(1e6f64, sep = ',', sign);
+    assert_eq!(TEST8, "+1,000,000");
+
+    const TEST9: &str = fmt_float!(f64::INFINITY, zero, width = 5, sep = ',');
+    assert_eq!(TEST9, "00inf");
+
+    const TEST10: &str = fmt_float!(f32::NAN, sign, width = 4);
+    assert_eq!(TEST10, " NaN");
+}
+
+#[test]
+fn constfmt_smoke() {
+    use constcat::{constfmt, fmt_float};
+
+    const TEST0: &str = constfmt!("");
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = "no args {{}}";
+    const TEST2: &str = constfmt!(TEST1);
+    assert_eq!(TEST2, "no args {}");
+
+    const TEST3: u16 = 8080;
+    const TEST4: &str = constfmt!("{}:{}{}", "localhost", TEST3, '/',);
+    assert_eq!(TEST4, "localhost:8080/");
+
+    const TEST5: &str = constfmt!("{1}{0}{1}{}", 'a', "b");
+    assert_eq!(TEST5, "baba");
+
+    const TEST6: &str = constfmt!("{x}={}, {y}={1}", -1i8, x = "x", y = true);
+    assert_eq!(TEST6, "x=-1, true=x");
+
+    const TEST7: &str = constfmt!("g ≈ {g}", g = fmt_float!(9.80665f64, precision = 2));
+    assert_eq!(TEST7, "g ≈ 9.81");
+}
+
+#[test]
+fn template_smoke() {
+    use constcat::template;
+
+    const TEST0: &str = template!("");
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = "orders";
+    const TEST2: u32 = 1000;
+    const TEST3: &str = template!(
+        include_str!("data/template.sql.in"),
+        APP => env!("CARGO_PKG_NAME"),
+        TABLE => TEST1,
+        MAX => TEST2,
+    );
+    assert_eq!(
+        TEST3,
+        "-- Generated for constcat, do not edit.
+CREATE TABLE orders (id INTEGER, price NUMERIC CHECK (price < $1000));
+SELECT $1 FROM orders;
+"
+    );
+
+    const TEST4: &str = template!("${A}${B}$${A}${A}$", A => 'x', B => "", C => "unused");
+    assert_eq!(TEST4, "x${A}x$");
+}